@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::AocError;
+
+// Known-good answers, keyed by day and part, so refactors can be checked
+// against them. The file is TOML with a table per day:
+//
+//     [day05]
+//     part1 = "CMZ"
+//     part2 = "MCD"
+//
+// Numbers can be written bare (`part1 = 24000`); they're compared as text.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, AocError> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|err: toml::de::Error| AocError::new(err.message().to_string()))?;
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| {
+                    AocError::new(format!("expected a table like [day01], found [{day_key}]"))
+                })?;
+            let parts = match parts {
+                toml::Value::Table(parts) => parts,
+                _ => return Err(AocError::new(format!("expected [{day_key}] to be a table"))),
+            };
+            for (part_key, answer) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => {
+                        return Err(AocError::new(format!(
+                            "expected part1 or part2 in [{day_key}], found {part_key}"
+                        )))
+                    }
+                };
+                let answer = match answer {
+                    toml::Value::String(answer) => answer,
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    _ => {
+                        return Err(AocError::new(format!(
+                            "expected {day_key}.{part_key} to be a string or a number"
+                        )))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // pad rather than write so the verify table can line these up
+        f.pad(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "fail",
+            Verdict::Missing => "missing",
+        })
+    }
+}
+
+// Trailing whitespace is ignored, so multi-line answers like day 10's screen
+// can be written as TOML multi-line strings.
+pub fn check(expected: Option<&str>, actual: &str) -> Verdict {
+    match expected {
+        Some(expected) if expected.trim_end() == actual.trim_end() => Verdict::Pass,
+        Some(_) => Verdict::Fail,
+        None => Verdict::Missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[day05]
+part1 = \"CMZ\"
+part2 = \"MCD\"
+
+[day14]
+part1 = 24
+",
+        )
+        .unwrap();
+        assert_eq!(answers.get(5, 1), Some("CMZ"));
+        assert_eq!(answers.get(5, 2), Some("MCD"));
+        assert_eq!(answers.get(14, 1), Some("24"));
+        assert_eq!(answers.get(14, 2), None);
+
+        let error = Answers::parse("[day05]\npart3 = \"CMZ\"\n").unwrap_err();
+        assert_eq!(
+            error.message,
+            "expected part1 or part2 in [day05], found part3"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("24"), "24"), Verdict::Pass);
+        assert_eq!(check(Some("#..#\n#..#\n"), "#..#\n#..#"), Verdict::Pass);
+        assert_eq!(check(Some("24"), "93"), Verdict::Fail);
+        assert_eq!(check(None, "24"), Verdict::Missing);
+    }
+}
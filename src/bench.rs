@@ -0,0 +1,74 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::AocError;
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let total: Duration = samples.iter().sum();
+        Stats {
+            mean: total / samples.len() as u32,
+            min: *samples.iter().min().unwrap(),
+            max: *samples.iter().max().unwrap(),
+        }
+    }
+}
+
+// How long each phase of a day took over a run of iterations.
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+// Times parse, part 1 and part 2 separately. Every iteration parses afresh so
+// the parse numbers include allocating the input, same as a real run.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Timings, AocError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(input)?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        part2.push(start.elapsed());
+    }
+    Ok(Timings {
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [3, 1, 8].map(Duration::from_millis);
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Stats {
+                mean: Duration::from_millis(4),
+                min: Duration::from_millis(1),
+                max: Duration::from_millis(8),
+            }
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use crate::error::{parse_number, AocError};
+use crate::generate::{Generate, Rng};
+use crate::parse::records;
+use crate::solution::{Solution, Streaming};
+
+// The `k` biggest totals pushed so far. Only ever holds `k` of them at once,
+// in a min-heap whose smallest gets bumped by anything bigger, so it takes the
+// same memory however many elves go past. Totals are i64, since enough snacks
+// add up to more than an i32 holds.
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<i64>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> TopK {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, total: i64) {
+        self.heap.push(Reverse(total));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    // biggest first
+    pub fn into_vec(self) -> Vec<i64> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(total)| total)
+            .collect()
+    }
+}
+
+fn total(snacks: &[i32]) -> i64 {
+    snacks.iter().map(|&snack| snack as i64).sum()
+}
+
+// Every elf's snacks, in the order they're listed, with the elves' totals
+// sorted once up front so ranks and percentiles don't need to sort again.
+#[derive(Debug)]
+pub struct ElfInventory {
+    elves: Vec<Vec<i32>>,
+    sorted_totals: Vec<i64>,
+}
+
+impl ElfInventory {
+    pub fn new(elves: Vec<Vec<i32>>) -> ElfInventory {
+        let mut sorted_totals: Vec<i64> = elves.iter().map(|snacks| total(snacks)).collect();
+        sorted_totals.sort();
+        ElfInventory {
+            elves,
+            sorted_totals,
+        }
+    }
+
+    pub fn elves(&self) -> &[Vec<i32>] {
+        &self.elves
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    pub fn total(&self, elf: usize) -> i64 {
+        total(&self.elves[elf])
+    }
+
+    // The `k` biggest totals, biggest first.
+    pub fn top_n(&self, k: usize) -> Vec<i64> {
+        let mut top = TopK::new(k);
+        for elf in 0..self.len() {
+            top.push(self.total(elf));
+        }
+        top.into_vec()
+    }
+
+    // Where `elf` comes when everyone's sorted by total, 1 for the most.
+    // Elves with the same total share the better rank.
+    pub fn rank(&self, elf: usize) -> usize {
+        let total = self.total(elf);
+        let carrying_more =
+            self.sorted_totals.len() - self.sorted_totals.partition_point(|&t| t <= total);
+        carrying_more + 1
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum: i64 = self.sorted_totals.iter().sum();
+        Some(sum as f64 / self.len() as f64)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    // The total `p` percent of the way from the smallest to the biggest,
+    // interpolating between the two nearest elves. None if there are no
+    // elves, or `p` isn't between 0 and 100.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let last = self.sorted_totals.len().checked_sub(1)?;
+        let position = p / 100.0 * last as f64;
+        let below = position.floor() as usize;
+        let above = position.ceil() as usize;
+        let fraction = position - below as f64;
+        let (low, high) = (
+            self.sorted_totals[below] as f64,
+            self.sorted_totals[above] as f64,
+        );
+        Some(low + (high - low) * fraction)
+    }
+
+    // How many elves carry totals in each run of `width` calories, as
+    // (first calorie count in the bucket, elves), from the smallest total's
+    // bucket to the biggest's, empty buckets included.
+    pub fn histogram(&self, width: i64) -> Vec<(i64, usize)> {
+        assert!(width > 0, "buckets have to be at least 1 calorie wide");
+        let (Some(&min), Some(&max)) = (self.sorted_totals.first(), self.sorted_totals.last())
+        else {
+            return Vec::new();
+        };
+        let bucket = |total: i64| total.div_euclid(width);
+        let first = bucket(min);
+        let mut counts = vec![0; (bucket(max) - first) as usize + 1];
+        for &total in &self.sorted_totals {
+            counts[(bucket(total) - first) as usize] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| ((first + i as i64) * width, count))
+            .collect()
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = ElfInventory;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<ElfInventory, AocError> {
+        let mut elves = Vec::new();
+        for elf in records(input) {
+            let snacks = elf
+                .numbered()
+                .map(|(line_number, line)| {
+                    parse_number::<i32>(line).map_err(|err| err.at_line(line_number))
+                })
+                .collect::<Result<Vec<i32>, AocError>>()?;
+            elves.push(snacks);
+        }
+        if elves.is_empty() {
+            return Err(AocError::new("there are no elves in the input"));
+        }
+        Ok(ElfInventory::new(elves))
+    }
+
+    fn part1(inventory: &ElfInventory) -> i64 {
+        *inventory
+            .top_n(1)
+            .first()
+            .expect("parse checked there's at least one elf")
+    }
+
+    fn part2(inventory: &ElfInventory) -> i64 {
+        inventory.top_n(3).iter().sum()
+    }
+}
+
+// Adds up each elf's snacks as the lines go by and keeps only the `k` biggest
+// totals, so the input can be any size. Blank lines between elves work the
+// same as in `records`, and CRLF line endings are fine.
+pub fn stream_top_n(reader: &mut dyn BufRead, k: usize) -> Result<Vec<i64>, AocError> {
+    let mut top = TopK::new(k);
+    let mut current: Option<i64> = None;
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        line_number += 1;
+        let read = reader.read_line(&mut line).map_err(|err| {
+            AocError::new(format!("could not read the input: {err}")).at_line(line_number)
+        })?;
+        if read == 0 {
+            break;
+        }
+        let snack = line.trim();
+        if snack.is_empty() {
+            if let Some(total) = current.take() {
+                top.push(total);
+            }
+        } else {
+            let calories = parse_number::<i32>(snack).map_err(|err| err.at_line(line_number))?;
+            *current.get_or_insert(0) += calories as i64;
+        }
+    }
+    if let Some(total) = current {
+        top.push(total);
+    }
+    Ok(top.into_vec())
+}
+
+impl Streaming for Day01 {
+    fn stream(reader: &mut dyn BufRead) -> Result<(i64, i64), AocError> {
+        let top = stream_top_n(reader, 3)?;
+        let biggest = *top
+            .first()
+            .ok_or_else(|| AocError::new("there are no elves in the input"))?;
+        Ok((biggest, top.iter().sum()))
+    }
+}
+
+impl Generate for Day01 {
+    // `size` elves (at least the three part 2 needs), each carrying a few snacks
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let snacks: Vec<String> = (0..rng.between(1, 6))
+                    .map(|_| rng.between(1000, 60000).to_string())
+                    .collect();
+                snacks.join("\n")
+            })
+            .collect();
+        elves.join("\n\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day01,
+        example: "01/example.txt" { part1 = 24000, part2 = 45000 },
+    }
+
+    // picks off the biggest elf `n` times over
+    fn naive_top(input: &str, n: usize) -> i64 {
+        let mut elves: Vec<i64> = input
+            .split("\n\n")
+            .map(|elf| elf.lines().map(|line| line.parse::<i64>().unwrap()).sum())
+            .collect();
+        let mut total = 0;
+        for _ in 0..n {
+            let biggest = (0..elves.len()).max_by_key(|&i| elves[i]).unwrap();
+            total += elves.remove(biggest);
+        }
+        total
+    }
+
+    #[test]
+    fn test_inventory() {
+        let input = include_str!("../../examples/01/example.txt");
+        let inventory = Day01::parse(input).unwrap();
+        assert_eq!(inventory.len(), 5);
+        assert_eq!(inventory.elves()[1], vec![4000]);
+        assert_eq!(inventory.top_n(2), vec![24000, 11000]);
+        assert_eq!(inventory.top_n(10).len(), 5);
+        assert_eq!(inventory.rank(3), 1);
+        assert_eq!(inventory.rank(1), 5);
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(inventory.percentile(0.0), Some(4000.0));
+        assert_eq!(inventory.percentile(87.5), Some(17500.0));
+        assert_eq!(inventory.percentile(100.5), None);
+        assert_eq!(inventory.percentile(f64::NAN), None);
+        assert_eq!(
+            inventory.histogram(5000),
+            vec![(0, 1), (5000, 1), (10000, 2), (15000, 0), (20000, 1)]
+        );
+
+        // the last elf counts even without a blank line after it
+        let inventory = Day01::parse("100\n\n200\n300").unwrap();
+        assert_eq!(inventory.elves(), [vec![100], vec![200, 300]]);
+        assert_eq!(inventory.rank(0), 2);
+
+        let error = Day01::parse("\n\n").unwrap_err();
+        assert_eq!(error.to_string(), "there are no elves in the input");
+        let inventory = ElfInventory::new(Vec::new());
+        assert!(inventory.top_n(3).is_empty());
+        assert_eq!(inventory.median(), None);
+        assert!(inventory.histogram(10).is_empty());
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut reader = "1000\r\n2000\r\n\r\n\r\n4000\r\n\r\n500".as_bytes();
+        assert_eq!(stream_top_n(&mut reader, 2).unwrap(), vec![4000, 3000]);
+
+        let error = stream_top_n(&mut "1\n\n2\nlots\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(error.to_string(), "line 4: expected a number, found 'lots'");
+
+        // a few big snacks add up past what an i32 can hold
+        let answers = Day01::stream(&mut "2000000000\n2000000000\n\n1\n".as_bytes()).unwrap();
+        assert_eq!(answers, (4000000000, 4000000001));
+
+        let error = Day01::stream(&mut "\n\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "there are no elves in the input");
+
+        // the same answers as parsing everything and sorting it
+        for (seed, input) in seeded_inputs::<Day01>(20, 200) {
+            let inventory = Day01::parse(&input).unwrap();
+            let answers = Day01::stream(&mut input.as_bytes()).unwrap();
+            assert_eq!(
+                answers,
+                (Day01::part1(&inventory), Day01::part2(&inventory)),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day01>(20, 50) {
+            let elves = Day01::parse(&input).unwrap();
+            assert_eq!(Day01::part1(&elves), naive_top(&input, 1), "seed {seed}");
+            assert_eq!(Day01::part2(&elves), naive_top(&input, 3), "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,791 @@
+use std::cmp::Reverse;
+
+use crate::error::AocError;
+use crate::generate::{Generate, Rng};
+use crate::solution::{RuleSets, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    // each one beats the one before it, and rock beats scissors
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    pub fn score(self) -> i32 {
+        self as i32 + 1
+    }
+
+    // the shape this one beats
+    pub fn beats(self) -> Shape {
+        Shape::ALL[(self as usize + 2) % 3]
+    }
+
+    // the shape that beats this one
+    pub fn loses_to(self) -> Shape {
+        Shape::ALL[(self as usize + 1) % 3]
+    }
+
+    pub fn against(self, theirs: Shape) -> Outcome {
+        if self.beats() == theirs {
+            Outcome::Win
+        } else if self.loses_to() == theirs {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn score(self) -> i32 {
+        self as i32 * 3
+    }
+}
+
+// One of a game's shapes, by its place in the order of the game's letters.
+// It only means anything alongside the Rules it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ShapeId(usize);
+
+impl ShapeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl From<Shape> for ShapeId {
+    // the classic rules list their shapes in Shape's order
+    fn from(shape: Shape) -> ShapeId {
+        ShapeId(shape as usize)
+    }
+}
+
+// A rock-paper-scissors style game as data: its shapes in the order of
+// their letters, what playing each one scores, which beats which, and what
+// losing, drawing and winning score.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    names: Vec<&'static str>,
+    shape_scores: Vec<i32>,
+    // beats[a][b] is whether shape a beats shape b; anything else draws
+    beats: Vec<Vec<bool>>,
+    outcome_scores: [i32; 3],
+}
+
+impl Rules {
+    // Shapes score 1, 2, 3, ... in order, and outcomes score as in the puzzle.
+    // `wins` lists who beats whom, by name.
+    pub fn new(names: &[&'static str], wins: &[(&str, &str)]) -> Rules {
+        assert!(
+            names.len() <= 26,
+            "there aren't enough letters for {} shapes",
+            names.len()
+        );
+        let index = |name: &str| {
+            names
+                .iter()
+                .position(|&n| n == name)
+                .unwrap_or_else(|| panic!("there's no shape called {name}"))
+        };
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        for &(winner, loser) in wins {
+            let (winner, loser) = (index(winner), index(loser));
+            assert!(
+                !beats[loser][winner],
+                "{} and {} both beat each other",
+                names[winner], names[loser]
+            );
+            assert!(winner != loser, "{} can't beat itself", names[winner]);
+            beats[winner][loser] = true;
+        }
+        Rules {
+            names: names.to_vec(),
+            shape_scores: (1..=names.len() as i32).collect(),
+            beats,
+            outcome_scores: Outcome::ALL.map(Outcome::score),
+        }
+    }
+
+    // The same game with different scores.
+    pub fn scored(mut self, shape_scores: &[i32], outcome_scores: [i32; 3]) -> Rules {
+        assert_eq!(shape_scores.len(), self.len(), "every shape needs a score");
+        self.shape_scores = shape_scores.to_vec();
+        self.outcome_scores = outcome_scores;
+        self
+    }
+
+    // Rock, paper and scissors, as the puzzle plays it.
+    pub fn classic() -> Rules {
+        let name = |shape: Shape| match shape {
+            Shape::Rock => "Rock",
+            Shape::Paper => "Paper",
+            Shape::Scissors => "Scissors",
+        };
+        let wins = Shape::ALL.map(|shape| (name(shape), name(shape.beats())));
+        Rules::new(&Shape::ALL.map(name), &wins)
+    }
+
+    // Rock-paper-scissors-lizard-Spock, where every shape beats two others.
+    pub fn rpsls() -> Rules {
+        Rules::new(
+            &["Rock", "Paper", "Scissors", "Lizard", "Spock"],
+            &[
+                ("Scissors", "Paper"),
+                ("Paper", "Rock"),
+                ("Rock", "Lizard"),
+                ("Lizard", "Spock"),
+                ("Spock", "Scissors"),
+                ("Scissors", "Lizard"),
+                ("Lizard", "Paper"),
+                ("Paper", "Spock"),
+                ("Spock", "Rock"),
+                ("Rock", "Scissors"),
+            ],
+        )
+    }
+
+    // The built-in rule sets by name, for picking one from outside the code.
+    pub fn named(name: &str) -> Option<Rules> {
+        match name {
+            "classic" => Some(Rules::classic()),
+            "rpsls" => Some(Rules::rpsls()),
+            _ => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = ShapeId> {
+        (0..self.len()).map(ShapeId)
+    }
+
+    // The shape at `index` in this game's order.
+    pub fn shape(&self, index: usize) -> ShapeId {
+        assert!(index < self.len(), "there are only {} shapes", self.len());
+        ShapeId(index)
+    }
+
+    pub fn name(&self, shape: ShapeId) -> &'static str {
+        self.names[shape.0]
+    }
+
+    pub fn outcome(&self, mine: ShapeId, theirs: ShapeId) -> Outcome {
+        if self.beats[mine.0][theirs.0] {
+            Outcome::Win
+        } else if self.beats[theirs.0][mine.0] {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn score(&self, mine: ShapeId, theirs: ShapeId) -> i32 {
+        self.shape_scores[mine.0] + self.outcome_scores[self.outcome(mine, theirs) as usize]
+    }
+
+    // The opponent's shapes are A, B, C, ... in order.
+    pub fn their_letter(&self, shape: ShapeId) -> char {
+        (b'A' + shape.0 as u8) as char
+    }
+
+    // The guide's hints end at Z, so the classic game has X, Y and Z and
+    // RPSLS has V to Z.
+    pub fn hint_letter(&self, hint: usize) -> char {
+        (b'Z' + 1 - self.len() as u8 + hint as u8) as char
+    }
+}
+
+// Lists letters for an error message: 'A', 'B' or 'C'.
+fn letter_list(letters: impl Iterator<Item = char>) -> String {
+    let letters: Vec<String> = letters.map(|c| format!("'{c}'")).collect();
+    match letters.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => letters.join(""),
+    }
+}
+
+// One line of the strategy guide: the opponent's shape, and the guide's hint
+// for us, counting from the first hint letter (X in the classic game). What
+// the hint means is up to the interpretation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Round {
+    pub theirs: ShapeId,
+    pub hint: usize,
+}
+
+// A strategy guide read under some game's rules.
+#[derive(Debug)]
+pub struct Guide {
+    pub rules: Rules,
+    pub rounds: Vec<Round>,
+}
+
+impl Guide {
+    pub fn parse(input: &str, rules: Rules) -> Result<Guide, AocError> {
+        let rounds = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| parse_round(&rules, line).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<Vec<Round>, AocError>>()?;
+        Ok(Guide { rules, rounds })
+    }
+
+    pub fn score(&self, interpretation: &dyn StrategyInterpretation) -> Result<i32, AocError> {
+        let mut total = 0;
+        for round in &self.rounds {
+            let mine = interpretation.respond(&self.rules, round.theirs, round.hint)?;
+            total += self.rules.score(mine, round.theirs);
+        }
+        Ok(total)
+    }
+}
+
+fn parse_round(rules: &Rules, line: &str) -> Result<Round, AocError> {
+    let mut chars = line.chars();
+    let theirs = match (chars.next(), chars.next()) {
+        (Some(c), Some(' ')) => rules.shapes().find(|&shape| rules.their_letter(shape) == c),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        let letters = letter_list(rules.shapes().map(|shape| rules.their_letter(shape)));
+        AocError::new(format!("expected {letters}, then a space"))
+    })?;
+    let hints = 0..rules.len();
+    let hint = match (chars.next(), chars.next()) {
+        (Some(c), None) => hints.clone().find(|&hint| rules.hint_letter(hint) == c),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        let letters = letter_list(hints.map(|hint| rules.hint_letter(hint)));
+        AocError::new(format!("expected {letters}")).at_column(3)
+    })?;
+    Ok(Round { theirs, hint })
+}
+
+// A way of reading the hints in the second column of the strategy guide.
+pub trait StrategyInterpretation {
+    // The shape to play against `theirs`, or an error if the hint doesn't
+    // mean anything under this reading.
+    fn respond(&self, rules: &Rules, theirs: ShapeId, hint: usize) -> Result<ShapeId, AocError>;
+}
+
+// The hints name our shape, in order: X, Y and Z are rock, paper and scissors
+// (what part 1 assumes).
+pub struct MyShape;
+
+impl StrategyInterpretation for MyShape {
+    fn respond(&self, rules: &Rules, _theirs: ShapeId, hint: usize) -> Result<ShapeId, AocError> {
+        Ok(rules.shape(hint))
+    }
+}
+
+// The last three hints say whether to lose, draw or win (what the elf really
+// meant, with X, Y and Z). Where there's a choice of shapes, it's the one
+// that scores most.
+pub struct DesiredOutcome;
+
+impl StrategyInterpretation for DesiredOutcome {
+    fn respond(&self, rules: &Rules, theirs: ShapeId, hint: usize) -> Result<ShapeId, AocError> {
+        let outcome = (hint + 3)
+            .checked_sub(rules.len())
+            .and_then(|i| Outcome::ALL.get(i))
+            .ok_or_else(|| {
+                AocError::new(format!(
+                    "'{}' doesn't say whether to lose, draw or win",
+                    rules.hint_letter(hint)
+                ))
+            })?;
+        rules
+            .shapes()
+            .filter(|&mine| rules.outcome(mine, theirs) == *outcome)
+            .max_by_key(|&mine| rules.score(mine, theirs))
+            .ok_or_else(|| {
+                AocError::new(format!(
+                    "nothing gets a {outcome:?} against {}",
+                    rules.name(theirs)
+                ))
+            })
+    }
+}
+
+// The hints say how far to move round from the opponent's shape, with the
+// middle hint meaning stay put: in the classic game X, Y and Z are back one,
+// stay, or forward one.
+pub struct Shift;
+
+impl StrategyInterpretation for Shift {
+    fn respond(&self, rules: &Rules, theirs: ShapeId, hint: usize) -> Result<ShapeId, AocError> {
+        let middle = (rules.len() - 1) / 2;
+        Ok(rules.shape((theirs.0 + hint + rules.len() - middle) % rules.len()))
+    }
+}
+
+// What to play against each of the opponent's shapes, in the rules' order,
+// whatever the guide's hint says.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseTable(pub Vec<ShapeId>);
+
+impl ResponseTable {
+    // The highest-scoring answer to each shape, from the rules alone. Each
+    // answer only depends on the one shape it's up against, so how often a
+    // guide's opponent plays each shape makes no difference: no table scores
+    // any guide higher. For the best single shape against how often they
+    // play each one, see MixedStrategy::of_opponent and best_response.
+    pub fn best(rules: &Rules) -> ResponseTable {
+        ResponseTable(
+            rules
+                .shapes()
+                .map(|theirs| {
+                    rules
+                        .shapes()
+                        .max_by_key(|&mine| (rules.score(mine, theirs), Reverse(mine)))
+                        .unwrap()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl StrategyInterpretation for ResponseTable {
+    fn respond(&self, _rules: &Rules, theirs: ShapeId, _hint: usize) -> Result<ShapeId, AocError> {
+        Ok(self.0[theirs.0])
+    }
+}
+
+// Playing each shape with some probability, like an opponent who can't be
+// read round by round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixedStrategy {
+    weights: Vec<f64>,
+}
+
+impl MixedStrategy {
+    // `weights` needn't add up to 1; they're scaled so they do.
+    pub fn new(weights: &[f64]) -> MixedStrategy {
+        assert!(
+            weights.iter().all(|&w| w >= 0.0),
+            "weights can't be negative"
+        );
+        let total: f64 = weights.iter().sum();
+        assert!(total > 0.0, "some shape has to have a chance");
+        MixedStrategy {
+            weights: weights.iter().map(|w| w / total).collect(),
+        }
+    }
+
+    pub fn uniform(shapes: usize) -> MixedStrategy {
+        MixedStrategy::new(&vec![1.0; shapes])
+    }
+
+    pub fn pure(shapes: usize, shape: ShapeId) -> MixedStrategy {
+        let mut weights = vec![0.0; shapes];
+        weights[shape.0] = 1.0;
+        MixedStrategy::new(&weights)
+    }
+
+    // How often the opponent plays each shape in the guide, or None if the
+    // guide has no rounds to tell.
+    pub fn of_opponent(guide: &Guide) -> Option<MixedStrategy> {
+        if guide.rounds.is_empty() {
+            return None;
+        }
+        let mut counts = vec![0.0; guide.rules.len()];
+        for round in &guide.rounds {
+            counts[round.theirs.0] += 1.0;
+        }
+        Some(MixedStrategy::new(&counts))
+    }
+
+    pub fn chance(&self, shape: ShapeId) -> f64 {
+        self.weights[shape.0]
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> ShapeId {
+        let mut left = rng.fraction();
+        for (shape, &weight) in self.weights.iter().enumerate() {
+            if left < weight {
+                return ShapeId(shape);
+            }
+            left -= weight;
+        }
+        // rounding left a sliver past the end
+        ShapeId(self.weights.iter().rposition(|&w| w > 0.0).unwrap())
+    }
+
+    // The score a round of `mine` against `theirs` is worth on average.
+    pub fn expected_score(rules: &Rules, mine: &MixedStrategy, theirs: &MixedStrategy) -> f64 {
+        let mut expected = 0.0;
+        for a in rules.shapes() {
+            for b in rules.shapes() {
+                expected += mine.chance(a) * theirs.chance(b) * rules.score(a, b) as f64;
+            }
+        }
+        expected
+    }
+
+    // The single shape that scores most on average against `theirs`, and
+    // what it scores.
+    pub fn best_response(rules: &Rules, theirs: &MixedStrategy) -> (ShapeId, f64) {
+        rules
+            .shapes()
+            .map(|mine| {
+                let pure = MixedStrategy::pure(rules.len(), mine);
+                (mine, MixedStrategy::expected_score(rules, &pure, theirs))
+            })
+            .fold((ShapeId(0), f64::MIN), |best, next| {
+                if next.1 > best.1 {
+                    next
+                } else {
+                    best
+                }
+            })
+    }
+}
+
+// Both players' total scores over a run of rounds: what they should get on
+// average, and what they actually got.
+#[derive(Debug, PartialEq)]
+pub struct Tournament {
+    pub rounds: usize,
+    pub expected: [f64; 2],
+    pub observed: [i64; 2],
+}
+
+// Plays `rounds` rounds between two mixed strategies.
+pub fn simulate(
+    rules: &Rules,
+    players: [&MixedStrategy; 2],
+    rounds: usize,
+    rng: &mut Rng,
+) -> Tournament {
+    let [first, second] = players;
+    let mut observed = [0, 0];
+    for _ in 0..rounds {
+        let (a, b) = (first.sample(rng), second.sample(rng));
+        observed[0] += rules.score(a, b) as i64;
+        observed[1] += rules.score(b, a) as i64;
+    }
+    let per_round = [
+        MixedStrategy::expected_score(rules, first, second),
+        MixedStrategy::expected_score(rules, second, first),
+    ];
+    Tournament {
+        rounds,
+        expected: per_round.map(|score| score * rounds as f64),
+        observed,
+    }
+}
+
+// Reads the guide for both parts, so every hint has to be one part 2 can
+// read as an outcome too. RPSLS's V and W aren't.
+fn parse_puzzle(input: &str, rules: Rules) -> Result<Guide, AocError> {
+    let guide = Guide::parse(input, rules)?;
+    let line_numbers = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, _)| i + 1);
+    for (round, line_number) in guide.rounds.iter().zip(line_numbers) {
+        DesiredOutcome
+            .respond(&guide.rules, round.theirs, round.hint)
+            .map_err(|err| err.at_line(line_number).at_column(3))?;
+    }
+    Ok(guide)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Guide;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Guide, AocError> {
+        parse_puzzle(input, Rules::classic())
+    }
+
+    fn part1(guide: &Guide) -> i32 {
+        guide.score(&MyShape).expect("every hint names a shape")
+    }
+
+    fn part2(guide: &Guide) -> i32 {
+        guide
+            .score(&DesiredOutcome)
+            .expect("parse checked every hint is an outcome")
+    }
+}
+
+impl RuleSets for Day02 {
+    fn parse_with_rules(input: &str, rules: &str) -> Result<Guide, AocError> {
+        let rules = Rules::named(rules).ok_or_else(|| {
+            AocError::new(format!(
+                "there's no rule set called '{rules}'; try classic or rpsls"
+            ))
+        })?;
+        parse_puzzle(input, rules)
+    }
+}
+
+impl Generate for Day02 {
+    // `size` rounds of the strategy guide
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&["A", "B", "C"]),
+                    rng.pick(&["X", "Y", "Z"])
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+    use crate::solution::solve_with_rules;
+
+    crate::example_tests! {
+        Day02,
+        example: "02/example.txt" { part1 = 15, part2 = 12 },
+    }
+
+    // Scores by arithmetic instead of a table: with rock, paper and scissors
+    // as 0, 1 and 2, a shape beats the one just before it, wrapping round.
+    fn naive_score(input: &str, second_is_outcome: bool) -> i32 {
+        input
+            .lines()
+            .map(|line| {
+                let bytes = line.as_bytes();
+                let theirs = (bytes[0] - b'A') as i32;
+                let second = (bytes[2] - b'X') as i32;
+                // 0 for a loss, 1 for a draw, 2 for a win
+                let (mine, outcome) = if second_is_outcome {
+                    ((theirs + second + 2) % 3, second)
+                } else {
+                    (second, (second - theirs + 4) % 3)
+                };
+                mine + 1 + 3 * outcome
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_shapes() {
+        for shape in Shape::ALL {
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_eq!(shape.against(shape), Outcome::Draw);
+            assert_eq!(shape.against(shape.beats()), Outcome::Win);
+            assert_eq!(shape.against(shape.loses_to()), Outcome::Loss);
+        }
+        assert_eq!(Shape::Rock.beats(), Shape::Scissors);
+        assert_eq!(Shape::Rock.loses_to(), Shape::Paper);
+    }
+
+    #[test]
+    fn test_rules() {
+        // the classic rules agree with the shapes they're made from
+        let classic = Rules::classic();
+        for mine in Shape::ALL {
+            for theirs in Shape::ALL {
+                assert_eq!(
+                    classic.outcome(mine.into(), theirs.into()),
+                    mine.against(theirs)
+                );
+            }
+        }
+        assert_eq!(classic.score(Shape::Rock.into(), Shape::Scissors.into()), 7);
+
+        // every shape in RPSLS beats two and loses to two
+        let rpsls = Rules::rpsls();
+        for mine in rpsls.shapes() {
+            let wins = rpsls
+                .shapes()
+                .filter(|&theirs| rpsls.outcome(mine, theirs) == Outcome::Win);
+            assert_eq!(wins.count(), 2, "{}", rpsls.name(mine));
+        }
+        assert_eq!(rpsls.outcome(rpsls.shape(4), rpsls.shape(0)), Outcome::Win);
+        assert_eq!(rpsls.hint_letter(0), 'V');
+        assert_eq!(Rules::named("rpsls").map(|rules| rules.len()), Some(5));
+        assert!(Rules::named("chess").is_none());
+        let scored = Rules::rpsls().scored(&[1, 1, 1, 1, 1], [0, 1, 2]);
+        assert_eq!(scored.score(scored.shape(3), scored.shape(4)), 3);
+    }
+
+    #[test]
+    fn test_interpretations() {
+        let guide = Day02::parse("A Y\nB X\nC Z\n").unwrap();
+        let responses = |interpretation: &dyn StrategyInterpretation| -> Vec<usize> {
+            guide
+                .rounds
+                .iter()
+                .map(|r| {
+                    interpretation
+                        .respond(&guide.rules, r.theirs, r.hint)
+                        .unwrap()
+                        .index()
+                })
+                .collect()
+        };
+        assert_eq!(responses(&MyShape), vec![1, 0, 2]);
+        assert_eq!(responses(&DesiredOutcome), vec![0, 0, 0]);
+        assert_eq!(responses(&Shift), vec![0, 0, 0]);
+        assert_eq!(guide.score(&Shift).unwrap(), 4 + 1 + 7);
+    }
+
+    #[test]
+    fn test_rpsls_guide() {
+        let guide = Guide::parse("E V\nA Z\nD X\n", Rules::rpsls()).unwrap();
+        assert_eq!(
+            guide.rounds[0],
+            Round {
+                theirs: ShapeId(4),
+                hint: 0
+            }
+        );
+        // Rock loses to Spock, Spock beats Rock, Scissors beats Lizard
+        assert_eq!(guide.score(&MyShape).unwrap(), 1 + (5 + 6) + (3 + 6));
+        // Spock back two and Rock forward two are both Scissors, which loses
+        // to either, and Lizard staying put draws
+        assert_eq!(guide.score(&Shift).unwrap(), 3 + 3 + (4 + 3));
+        let error = guide.score(&DesiredOutcome).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "'V' doesn't say whether to lose, draw or win"
+        );
+
+        // winning against Rock could be Paper or Spock, so it's Spock
+        let guide = Guide::parse("A Z\nA Y\n", Rules::rpsls()).unwrap();
+        assert_eq!(guide.score(&DesiredOutcome).unwrap(), 11 + 4);
+
+        let error = Guide::parse("F X\n", Rules::rpsls()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: expected 'A', 'B', 'C', 'D' or 'E', then a space"
+        );
+    }
+
+    #[test]
+    fn test_pick_rules() {
+        let input = crate::input::normalize(include_str!("../../examples/02/rpsls.txt"));
+        assert_eq!(solve_with_rules::<Day02>(&input, 1, "rpsls").unwrap(), "35");
+        assert_eq!(solve_with_rules::<Day02>(&input, 2, "rpsls").unwrap(), "34");
+
+        // the same letters are different shapes in the two games: paper
+        // beating rock, then lizard losing to it
+        assert_eq!(solve_with_rules::<Day02>("A Y", 1, "classic").unwrap(), "8");
+        assert_eq!(solve_with_rules::<Day02>("A Y", 1, "rpsls").unwrap(), "4");
+
+        let error = Day02::parse_with_rules("A Y", "chess").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "there's no rule set called 'chess'; try classic or rpsls"
+        );
+        let error = Day02::parse_with_rules("A Y\nE V\n", "rpsls").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: 'V' doesn't say whether to lose, draw or win"
+        );
+    }
+
+    #[test]
+    fn test_best_responses() {
+        let classic = Rules::classic();
+        let table = ResponseTable::best(&classic);
+        let answers = [Shape::Paper, Shape::Scissors, Shape::Rock];
+        assert_eq!(table, ResponseTable(answers.map(ShapeId::from).to_vec()));
+        let guide = Day02::parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(guide.score(&table).unwrap(), 8 + 9 + 7);
+
+        // Spock beats rock and scissors for the most points
+        assert_eq!(ResponseTable::best(&Rules::rpsls()).0[0], ShapeId(4));
+
+        // against someone who mostly plays rock, paper is the answer
+        let guide = Day02::parse("A X\nA X\nA X\nC X\n").unwrap();
+        let theirs = MixedStrategy::of_opponent(&guide).unwrap();
+        assert_eq!(theirs.chance(Shape::Rock.into()), 0.75);
+        assert_eq!(
+            MixedStrategy::best_response(&classic, &theirs),
+            (Shape::Paper.into(), 6.5)
+        );
+        assert_eq!(MixedStrategy::of_opponent(&Day02::parse("").unwrap()), None);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let classic = Rules::classic();
+        let uniform = MixedStrategy::uniform(3);
+        let rock = MixedStrategy::pure(3, Shape::Rock.into());
+        let tournament = simulate(&classic, [&rock, &uniform], 10000, &mut Rng::new(2022));
+        // rock scores 1 and draws, wins and loses a third of the time each;
+        // the other side averages 2 for its shape and 3 for the outcome
+        for (expected, per_round) in tournament.expected.iter().zip([4.0, 5.0]) {
+            assert!((expected - per_round * 10000.0).abs() < 1e-6);
+        }
+        for player in 0..2 {
+            let error = tournament.observed[player] as f64 / tournament.expected[player] - 1.0;
+            assert!(error.abs() < 0.03, "player {player}: {tournament:?}");
+        }
+        // the same seed plays the same games
+        let again = simulate(&classic, [&rock, &uniform], 10000, &mut Rng::new(2022));
+        assert_eq!(tournament, again);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day02::parse("A Y\nF X\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected 'A', 'B' or 'C', then a space"
+        );
+        let error = Day02::parse("A Y\nB U\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected 'X', 'Y' or 'Z'"
+        );
+        let error = Day02::parse("A YY\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected 'X', 'Y' or 'Z'"
+        );
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day02>(20, 100) {
+            let guide = Day02::parse(&input).unwrap();
+            assert_eq!(
+                Day02::part1(&guide),
+                naive_score(&input, false),
+                "seed {seed}"
+            );
+            assert_eq!(
+                Day02::part2(&guide),
+                naive_score(&input, true),
+                "seed {seed}"
+            );
+        }
+    }
+}
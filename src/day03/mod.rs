@@ -0,0 +1,174 @@
+use core::str::Chars;
+use std::collections::HashSet;
+
+use crate::error::AocError;
+use crate::solution::Solution;
+
+fn get_char_value(c: char) -> u32 {
+    let mut c_value = u32::from(c);
+    if c_value > 96 {
+        // lowercase
+        c_value -= 96;
+    } else {
+        // uppercase
+        c_value -= 38;
+    }
+    c_value
+}
+
+fn find_duplicate(a: Chars, mut b: Chars) -> Option<char> {
+    let mut items_a: HashSet<char> = HashSet::new();
+    for ai in a {
+        items_a.insert(ai);
+    }
+    b.find(|bi| items_a.contains(bi))
+}
+
+fn find_triplicate(a: Chars, b: Chars, mut c: Chars) -> Option<char> {
+    let mut items_a: HashSet<char> = HashSet::new();
+    let mut a_b_overlap: HashSet<char> = HashSet::new();
+    for ai in a {
+        items_a.insert(ai);
+    }
+    for bi in b {
+        if items_a.contains(&bi) {
+            // the known set of overlaps between b and a
+            a_b_overlap.insert(bi);
+        }
+    }
+    // if it's present in b and a and matches with c...
+    c.find(|ci| a_b_overlap.contains(ci))
+}
+
+// a rucksack's two compartments
+fn halves(line: &str) -> (&str, &str) {
+    line.split_at(line.len() / 2)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, AocError> {
+        let mut lines = Vec::new();
+        let mut line_numbers = Vec::new();
+        for (i, line) in input.split("\n").enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            // item values only make sense for letters
+            if let Some(column) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(AocError::new("expected only letters in a rucksack")
+                    .at_line(i + 1)
+                    .at_column(column + 1));
+            }
+            if line.len() % 2 != 0 {
+                return Err(
+                    AocError::new("a rucksack's two compartments should be the same size")
+                        .at_line(i + 1),
+                );
+            }
+            let (bag1, bag2) = halves(line);
+            if find_duplicate(bag1.chars(), bag2.chars()).is_none() {
+                return Err(
+                    AocError::new("no item is in both compartments of this rucksack")
+                        .at_line(i + 1),
+                );
+            }
+            lines.push(line.to_string());
+            line_numbers.push(i + 1);
+        }
+
+        if !lines.len().is_multiple_of(3) {
+            return Err(AocError::new(format!(
+                "expected rucksacks in groups of three, but there are {}",
+                lines.len()
+            )));
+        }
+        for (group, numbers) in lines.chunks(3).zip(line_numbers.chunks(3)) {
+            if find_triplicate(group[0].chars(), group[1].chars(), group[2].chars()).is_none() {
+                return Err(AocError::new(format!(
+                    "no badge is in all three rucksacks of the group starting at line {}",
+                    numbers[0]
+                ))
+                .at_line(numbers[2]));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<String>) -> u32 {
+        let mut total_misplaced_value = 0;
+        for line in lines {
+            let (bag1, bag2) = halves(line);
+            let dup = find_duplicate(bag1.chars(), bag2.chars())
+                .expect("parse checked every rucksack has a misplaced item");
+            total_misplaced_value += get_char_value(dup);
+        }
+        total_misplaced_value
+    }
+
+    fn part2(lines: &Vec<String>) -> u32 {
+        let mut total_badges_value = 0;
+
+        let mut set_of_bags: Vec<&str> = Vec::new();
+
+        for line in lines {
+            if set_of_bags.len() == 2 {
+                set_of_bags.push(line);
+
+                let badge = find_triplicate(
+                    set_of_bags[0].chars(),
+                    set_of_bags[1].chars(),
+                    set_of_bags[2].chars(),
+                )
+                .expect("parse checked every group has a badge");
+                total_badges_value += get_char_value(badge);
+
+                // reset for next threesome of bags
+                set_of_bags = Vec::new();
+            } else {
+                set_of_bags.push(line);
+            }
+        }
+
+        total_badges_value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day03,
+        example: "03/example.txt" { part1 = 157, part2 = 70 },
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day03::parse("abcA\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: no item is in both compartments of this rucksack"
+        );
+        let error = Day03::parse("aa\nabc\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: a rucksack's two compartments should be the same size"
+        );
+        let error = Day03::parse("aa\nbb\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected rucksacks in groups of three, but there are 2"
+        );
+        let error = Day03::parse("aa\nbb\naa\naa\n\nbb\ncc\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: no badge is in all three rucksacks of the group starting at line 1"
+        );
+    }
+}
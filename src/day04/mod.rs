@@ -0,0 +1,67 @@
+use crate::error::{parse_number, AocError};
+use crate::interval_set::Interval;
+use crate::solution::Solution;
+
+type AssignmentPair = [Interval; 2];
+
+fn parse_line(line: &str) -> Result<AssignmentPair, AocError> {
+    let expected = || AocError::new("expected 'A-B,C-D'");
+    let (first, second) = line.split_once(",").ok_or_else(expected)?;
+    let parse_range = |assignment: &str| {
+        let (start, end) = assignment.split_once("-").ok_or_else(expected)?;
+        let (start, end) = (parse_number(start)?, parse_number(end)?);
+        if start > end {
+            return Err(AocError::new(format!("section {start} comes after {end}")));
+        }
+        Ok(Interval::new(start, end))
+    };
+    Ok([parse_range(first)?, parse_range(second)?])
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<AssignmentPair>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<AssignmentPair>, AocError> {
+        let mut pairs = Vec::new();
+        for (i, line) in input.split("\n").enumerate() {
+            if !line.is_empty() {
+                pairs.push(parse_line(line).map_err(|err| err.at_line(i + 1))?);
+            }
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Vec<AssignmentPair>) -> usize {
+        let mut subsumed_count = 0;
+        for [range1, range2] in pairs {
+            if range1.is_subset(range2) || range2.is_subset(range1) {
+                subsumed_count += 1;
+            }
+        }
+        subsumed_count
+    }
+
+    fn part2(pairs: &Vec<AssignmentPair>) -> usize {
+        let mut overlapping_count = 0;
+        for [range1, range2] in pairs {
+            if range1.overlaps(range2) {
+                overlapping_count += 1;
+            }
+        }
+        overlapping_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day04,
+        example: "04/example.txt" { part1 = 2, part2 = 4 },
+    }
+}
@@ -0,0 +1,347 @@
+use crate::error::AocError;
+use crate::generate::{Generate, Rng};
+use crate::parse::records;
+use crate::solution::Solution;
+
+type BoatStack = Vec<char>;
+
+type Boat = Vec<BoatStack>;
+
+#[derive(Debug)]
+struct Instruction {
+    number_containers: u32,
+    boat_from: usize,
+    boat_to: usize,
+}
+
+fn make_boat(boat_lines: &mut Vec<&str>) -> Result<Boat, AocError> {
+    let mut boat: Boat = Vec::new();
+    let crate_size = 4;
+
+    // pop off the column numbers, we don't need them
+    boat_lines.pop();
+    // reverse it so we stack things bottom-up
+    boat_lines.reverse();
+
+    let line_count = boat_lines.len();
+    for (line_index, line) in boat_lines.iter().enumerate() {
+        // the boat drawing starts the file, so count back up from the bottom
+        let line_number = line_count - line_index;
+        // a single layer of the stacks
+        for (index, char) in line.chars().enumerate() {
+            if char != ' ' && char != '[' && char != ']' && char != '\n' {
+                // we've found a container's letter contents
+                if index % crate_size != 1 {
+                    return Err(
+                        AocError::new("expected crates like '[A]' lined up in columns")
+                            .at_line(line_number)
+                            .at_column(index + 1),
+                    );
+                }
+                // divide so we get which boat stack to stack this on
+                let boat_index = (index - 1) / crate_size;
+                if boat.len() == boat_index {
+                    let new_boat_stack: BoatStack = Vec::new();
+                    boat.push(new_boat_stack);
+                }
+                if boat.len() < boat_index {
+                    return Err(AocError::new(format!(
+                        "stack {} appears to be missing from the boat",
+                        boat.len() + 1
+                    ))
+                    .at_line(line_number)
+                    .at_column(index + 1));
+                }
+                boat[boat_index].push(char);
+            }
+        }
+    }
+    Ok(boat)
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, AocError> {
+    const EXPECTED: &str = "move N from A to B";
+    let (number_containers, boat_from, boat_to): (u32, usize, usize) =
+        crate::scan!(line, "move {} from {} to {}", EXPECTED)?;
+    if boat_from == 0 || boat_to == 0 {
+        // stacks are numbered from 1
+        return Err(AocError::new(format!("expected '{EXPECTED}'")));
+    }
+    let inst = Instruction {
+        number_containers,
+        boat_from: boat_from - 1,
+        boat_to: boat_to - 1,
+    };
+    Ok(inst)
+}
+
+// parse checked every move has enough crates to take
+fn take_crates(boat: &mut Boat, instruction: &Instruction) -> Vec<char> {
+    let from = &mut boat[instruction.boat_from];
+    from.split_off(from.len() - instruction.number_containers as usize)
+}
+
+fn use_crane(boat: &mut Boat, instruction: &Instruction) {
+    // one at a time, so they land upside down
+    let mut moved_stack = take_crates(boat, instruction);
+    moved_stack.reverse();
+    boat[instruction.boat_to].append(&mut moved_stack);
+}
+
+fn use_new_crane(boat: &mut Boat, instruction: &Instruction) {
+    let mut moved_stack = take_crates(boat, instruction);
+    boat[instruction.boat_to].append(&mut moved_stack);
+}
+
+// parse checked no stack ends up empty
+fn get_answer(boat: &Boat) -> String {
+    boat.iter().filter_map(|stack| stack.last()).collect()
+}
+
+pub struct Manifest {
+    boat: Boat,
+    instructions: Vec<Instruction>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Manifest;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Manifest, AocError> {
+        // the boat drawing, then the instructions
+        let mut records = records(input);
+        let mut boat_lines = records.next().map_or(Vec::new(), |record| record.lines);
+        let instruction_lines = records.next();
+        if let Some(extra) = records.next() {
+            return Err(AocError::new("expected a boat and a list of instructions")
+                .at_line(extra.first_line));
+        }
+        let boat = make_boat(&mut boat_lines)?;
+        log::debug!("Boat:\n{boat:#?}");
+
+        // both cranes move the same number of crates each time, so the
+        // stacks' heights say whether every move has the crates it needs
+        let mut heights: Vec<usize> = boat.iter().map(|stack| stack.len()).collect();
+        let mut instructions = Vec::new();
+        for (line_number, line) in instruction_lines
+            .iter()
+            .flat_map(|record| record.numbered())
+        {
+            let inst = parse_instruction(line).map_err(|err| err.at_line(line_number))?;
+            for stack in [inst.boat_from, inst.boat_to] {
+                if stack >= boat.len() {
+                    return Err(AocError::new(format!("there is no stack {}", stack + 1))
+                        .at_line(line_number));
+                }
+            }
+            let moved = inst.number_containers as usize;
+            if heights[inst.boat_from] < moved {
+                return Err(AocError::new(format!(
+                    "stack {} only has {} crates to move by then",
+                    inst.boat_from + 1,
+                    heights[inst.boat_from]
+                ))
+                .at_line(line_number));
+            }
+            heights[inst.boat_from] -= moved;
+            heights[inst.boat_to] += moved;
+            instructions.push(inst);
+        }
+        if let Some(empty) = heights.iter().position(|&height| height == 0) {
+            return Err(AocError::new(format!(
+                "stack {} ends up empty, so there's no crate on top to read",
+                empty + 1
+            )));
+        }
+        Ok(Manifest { boat, instructions })
+    }
+
+    fn part1(manifest: &Manifest) -> String {
+        let mut boat = manifest.boat.clone();
+
+        for inst in &manifest.instructions {
+            log::trace!("{inst:#?}");
+            use_crane(&mut boat, inst);
+        }
+
+        log::debug!("Boat:\n{boat:#?}");
+
+        get_answer(&boat)
+    }
+
+    fn part2(manifest: &Manifest) -> String {
+        let mut boat = manifest.boat.clone();
+
+        for inst in &manifest.instructions {
+            use_new_crane(&mut boat, inst);
+        }
+
+        get_answer(&boat)
+    }
+}
+
+impl Generate for Day05 {
+    // A boat of three to nine stacks and `size` moves. No move ever takes a
+    // stack's last crate, so there's always a top crate to read off at the end.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut stacks: Vec<Vec<char>> = (0..rng.between(3, 9))
+            .map(|_| {
+                (0..rng.between(2, 8))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+
+        let height = stacks.iter().map(|stack| stack.len()).max().unwrap();
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                let cells: Vec<String> = stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(c) => format!("[{c}]"),
+                        None => "   ".to_string(),
+                    })
+                    .collect();
+                cells.join(" ")
+            })
+            .collect();
+        let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {n} ")).collect();
+        lines.push(numbers.join(" "));
+        lines.push(String::new());
+
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.below(stacks.len());
+                if stacks[from].len() > 1 {
+                    break from;
+                }
+            };
+            let to = (from + 1 + rng.below(stacks.len() - 1)) % stacks.len();
+            let count = rng.between(1, stacks[from].len() as i64 - 1) as usize;
+            let keep = stacks[from].len() - count;
+            let moved = stacks[from].split_off(keep);
+            stacks[to].extend(moved);
+            lines.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day05,
+        example: "05/example.txt" { part1 = "CMZ", part2 = "MCD" },
+    }
+
+    // Reads the columns straight out of the drawing, then moves whole runs of
+    // crates at once, flipping them over if the crane can only lift one.
+    fn naive_tops(input: &str, one_at_a_time: bool) -> String {
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+        let mut rows: Vec<&str> = drawing.lines().collect();
+        let count = rows.pop().unwrap().split_whitespace().count();
+        let mut stacks = vec![String::new(); count];
+        for row in rows.iter().rev() {
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                match row.chars().nth(1 + 4 * i) {
+                    Some(c) if c != ' ' => stack.push(c),
+                    _ => {}
+                }
+            }
+        }
+        for line in moves.lines() {
+            let words: Vec<usize> = line.split(' ').filter_map(|w| w.parse().ok()).collect();
+            let (count, from, to) = (words[0], words[1] - 1, words[2] - 1);
+            let keep = stacks[from].len() - count;
+            let mut moved = stacks[from].split_off(keep);
+            if one_at_a_time {
+                moved = moved.chars().rev().collect();
+            }
+            stacks[to].push_str(&moved);
+        }
+        stacks
+            .iter()
+            .map(|stack| stack.chars().last().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day05>(20, 100) {
+            let manifest = Day05::parse(&input).unwrap();
+            assert_eq!(
+                Day05::part1(&manifest),
+                naive_tops(&input, true),
+                "seed {seed}"
+            );
+            assert_eq!(
+                Day05::part2(&manifest),
+                naive_tops(&input, false),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move three from 1 to 3
+";
+        let error = Day05::parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 5: expected a number, found 'three'"
+        );
+
+        let input = "[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+shift 3 from 1 to 3
+";
+        let error = Day05::parse(input).err().unwrap();
+        assert_eq!(error.to_string(), "line 5: expected 'move N from A to B'");
+
+        let input = "[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 4
+";
+        let error = Day05::parse(input).err().unwrap();
+        assert_eq!(error.to_string(), "line 4: there is no stack 4");
+
+        let input = "    [D]
+[Z] [M] [P]
+ 1   2   3
+
+move 2 from 2 to 1
+move 2 from 2 to 3
+";
+        let error = Day05::parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 6: stack 2 only has 0 crates to move by then"
+        );
+
+        let input = "[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+";
+        let error = Day05::parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "stack 2 ends up empty, so there's no crate on top to read"
+        );
+    }
+}
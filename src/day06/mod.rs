@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use crate::error::AocError;
+use crate::solution::{Maybe, Solution};
+
+// How many characters have gone by at the end of the first `window_length`
+// all-different ones in a row, or None if they never turn up.
+fn find_start_of_message(contents: &str, window_length: usize) -> Option<usize> {
+    let chars: Vec<char> = contents.chars().collect();
+    let index = chars
+        .windows(window_length)
+        .position(|window| HashSet::<&char>::from_iter(window.iter()).len() == window_length)?;
+    log::debug!("Index: {}", index + window_length);
+    Some(index + window_length)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Output1 = Maybe<usize>;
+    type Output2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<String, AocError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(contents: &String) -> Maybe<usize> {
+        let window_length: usize = 4;
+        Maybe::new(find_start_of_message(contents, window_length), "no marker")
+    }
+
+    fn part2(contents: &String) -> Maybe<usize> {
+        let window_length: usize = 14;
+        Maybe::new(find_start_of_message(contents, window_length), "no marker")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day06,
+        example: "06/example.txt" { part1 = 7, part2 = 19 },
+        second: "06/second.txt" { part1 = 5, part2 = 23 },
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(find_start_of_message("abcd", 4), Some(4));
+        assert_eq!(find_start_of_message("abcabcabc", 4), None);
+        // too short to hold a whole window
+        assert_eq!(find_start_of_message("ab", 4), None);
+        assert_eq!(Day06::part1(&"abab".to_string()).to_string(), "no marker");
+    }
+}
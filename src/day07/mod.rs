@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_number, AocError};
+use crate::solution::Solution;
+use crate::tree::{NodeId, Tree};
+
+#[derive(Debug, Clone)]
+pub enum Entry {
+    Dir { name: String },
+    File { name: String, size: u64 },
+}
+
+impl Entry {
+    fn dir(name: &str) -> Entry {
+        Entry::Dir {
+            name: name.to_string(),
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Entry::Dir { name, .. } | Entry::File { name, .. } => name,
+        }
+    }
+
+    fn is_dir(&self) -> bool {
+        matches!(self, Entry::Dir { .. })
+    }
+}
+
+type Filesystem = Tree<Entry>;
+
+// directory sizes worked out so far, kept out of the tree so the parts only
+// ever need to read it
+type SizeMemo = HashMap<NodeId, u64>;
+
+// finds the directory `name` in `parent`, making it if we haven't seen it yet
+fn child_dir(fs: &mut Filesystem, parent: NodeId, name: &str) -> NodeId {
+    match fs.find_child(parent, |entry| entry.is_dir() && entry.name() == name) {
+        Some(dir) => dir,
+        None => fs.add_child(parent, Entry::dir(name)),
+    }
+}
+
+fn parse_line(fs: &mut Filesystem, location: NodeId, line: &str) -> Result<NodeId, AocError> {
+    let words: Vec<&str> = line.split(" ").collect();
+    match words[..] {
+        ["$", "cd", "/"] => Ok(fs.root()),
+        ["$", "cd", ".."] => fs
+            .parent(location)
+            .ok_or_else(|| AocError::new("can't go up from /")),
+        ["$", "cd", name] => Ok(child_dir(fs, location, name)),
+        ["$", "ls"] => Ok(location),
+        ["$", ..] => Err(AocError::new("expected '$ cd DIR' or '$ ls'")),
+        // we're getting back a listing of whatever the current location is,
+        // so add anything we haven't seen before
+        ["dir", name] => {
+            child_dir(fs, location, name);
+            Ok(location)
+        }
+        [size, name] => {
+            let size = parse_number::<u64>(size)?;
+            if fs
+                .find_child(location, |entry| entry.name() == name)
+                .is_none()
+            {
+                let name = name.to_string();
+                fs.add_child(location, Entry::File { name, size });
+            }
+            Ok(location)
+        }
+        _ => Err(AocError::new(
+            "expected a command, 'dir NAME' or 'SIZE NAME'",
+        )),
+    }
+}
+
+fn get_dir_size(fs: &Filesystem, dir: NodeId, memo: &mut SizeMemo) -> u64 {
+    // if this has had its size measured already, return memo
+    if let Some(&size) = memo.get(&dir) {
+        return size;
+    }
+
+    let mut size = 0;
+    for &child in fs.children(dir) {
+        size += match fs[child] {
+            // add direct files' sizes
+            Entry::File { size, .. } => size,
+            // then call this recursively on subfolders
+            Entry::Dir { .. } => get_dir_size(fs, child, memo),
+        };
+    }
+    // save this dir's size for future reference
+    memo.insert(dir, size);
+    size
+}
+
+fn dirs(fs: &Filesystem) -> Vec<NodeId> {
+    fs.descendants(fs.root())
+        .filter(|&id| fs[id].is_dir())
+        .collect()
+}
+
+const FS_SIZE: u64 = 70000000;
+const UPDATE_SIZE: u64 = 30000000;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Filesystem;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Filesystem, AocError> {
+        let mut fs = Tree::new(Entry::dir("/"));
+        let mut location = fs.root();
+        for (i, line) in input.lines().enumerate() {
+            location = parse_line(&mut fs, location, line).map_err(|err| err.at_line(i + 1))?;
+        }
+        Ok(fs)
+    }
+
+    fn part1(fs: &Filesystem) -> u64 {
+        let mut memo = SizeMemo::new();
+        let mut totals_under_100k = 0;
+        for dir in dirs(fs) {
+            let size = get_dir_size(fs, dir, &mut memo);
+            if size <= 100000 {
+                totals_under_100k += size;
+            }
+        }
+        totals_under_100k
+    }
+
+    fn part2(fs: &Filesystem) -> u64 {
+        let mut memo = SizeMemo::new();
+        let root_size = get_dir_size(fs, fs.root(), &mut memo);
+        let needed_spare_size = UPDATE_SIZE.saturating_sub(FS_SIZE.saturating_sub(root_size));
+
+        // since we already ran on root every size is memoised by now
+        dirs(fs)
+            .into_iter()
+            .map(|dir| get_dir_size(fs, dir, &mut memo))
+            .filter(|&size| size >= needed_spare_size)
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day07,
+        example: "07/example.txt" { part1 = 95437, part2 = 24933642 },
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day07::parse("$ cd /\n$ cd ..\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: can't go up from /");
+        let error = Day07::parse("$ cd /\n$ rm -rf a\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected '$ cd DIR' or '$ ls'");
+        let error = Day07::parse("$ ls\nbig b.txt\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected a number, found 'big'");
+    }
+}
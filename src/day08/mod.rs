@@ -0,0 +1,157 @@
+use crate::error::AocError;
+use crate::grid::{Grid, ORTHOGONAL};
+use crate::solution::Solution;
+
+#[derive(Debug, Clone)]
+pub struct Tree {
+    height: u32,
+    is_seen: bool,
+}
+
+fn make_tree_grid(contents: &str) -> Result<Grid<Tree>, AocError> {
+    Grid::from_str(contents, |char| match char.to_digit(10) {
+        Some(height) => Ok(Tree {
+            height,
+            is_seen: false,
+        }),
+        None => Err(AocError::new(format!(
+            "expected a tree height, found '{char}'"
+        ))),
+    })
+}
+
+fn get_row_visible_tree_count<'a>(row: impl Iterator<Item = &'a mut Tree>) -> u32 {
+    let mut count = 0;
+    let mut tallest_tree: Option<u32> = None;
+    for tree in row {
+        if tallest_tree.is_none_or(|tallest| tree.height > tallest) {
+            tallest_tree = Some(tree.height);
+            if !tree.is_seen {
+                count += 1;
+                tree.is_seen = true;
+            }
+        }
+    }
+    count
+}
+
+fn get_total_visible_tree_count(g: &mut Grid<Tree>) -> u32 {
+    let mut visible_count = 0;
+    // top and bottom
+    let mut i = 0;
+    while i < g.rows() {
+        let row = g.row_mut(i);
+        visible_count += get_row_visible_tree_count(row);
+        let rev_row = g.row_mut(i).rev();
+        visible_count += get_row_visible_tree_count(rev_row);
+        i += 1;
+    }
+    // left and right;
+    i = 0;
+    while i < g.cols() {
+        let col = g.col_mut(i);
+        visible_count += get_row_visible_tree_count(col);
+        let rev_col = g.col_mut(i).rev();
+        visible_count += get_row_visible_tree_count(rev_col);
+        i += 1;
+    }
+    visible_count
+}
+
+// How many trees you can see looking out from `pos` in one direction, up to
+// and including the first one at least as tall as the tree at `pos`.
+fn trees_from_point(grid: &Grid<Tree>, pos: (usize, usize), direction: (isize, isize)) -> u32 {
+    let mut count = 0;
+    let start_height = grid[pos].height;
+
+    for other in grid.ray(pos, direction) {
+        count += 1;
+        if grid[other].height >= start_height {
+            break;
+        }
+    }
+
+    count
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<Tree>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Grid<Tree>, AocError> {
+        make_tree_grid(input.trim())
+    }
+
+    fn part1(grid: &Grid<Tree>) -> u32 {
+        let mut grid = grid.clone();
+        get_total_visible_tree_count(&mut grid)
+    }
+
+    fn part2(grid: &Grid<Tree>) -> u32 {
+        let mut max_scenic_score = 0;
+
+        for pos in grid.positions() {
+            let score = ORTHOGONAL
+                .iter()
+                .map(|&direction| trees_from_point(grid, pos, direction))
+                .product();
+            if score > max_scenic_score {
+                max_scenic_score = score;
+            }
+        }
+        max_scenic_score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day08,
+        example: "08/example.txt" { part1 = 21, part2 = 8 },
+    }
+
+    #[test]
+    fn test_get_row_visible_tree_count() {
+        let sample_input = "30373
+25512
+65332
+33549
+35390";
+        let mut g = make_tree_grid(sample_input).unwrap();
+        let visible_tree_count = get_row_visible_tree_count(g.row_mut(0));
+        assert_eq!(visible_tree_count, 2);
+        // only find newly-visible trees when we run it again
+        let opposite_visible_tree_count = get_row_visible_tree_count(g.row_mut(0).rev());
+        assert_eq!(opposite_visible_tree_count, 1);
+    }
+
+    #[test]
+    fn test_get_col_visible_tree_count() {
+        let sample_input = "30373
+25512
+65332
+33549
+35390";
+        let mut g = make_tree_grid(sample_input).unwrap();
+        let visible_tree_count = get_row_visible_tree_count(g.col_mut(1));
+        assert_eq!(visible_tree_count, 2);
+        // only find newly-visible trees when we run it again
+        let opposite_visible_tree_count = get_row_visible_tree_count(g.col_mut(1).rev());
+        assert_eq!(opposite_visible_tree_count, 1);
+    }
+
+    #[test]
+    fn test_get_visible_tree_count() {
+        let sample_input = "12345
+12145
+12345";
+        let mut g = make_tree_grid(sample_input).unwrap();
+        let visible_tree_count = get_total_visible_tree_count(&mut g);
+        assert_eq!(visible_tree_count, 14);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::error::{parse_number, AocError};
+use crate::frames::{Animated, Recorder};
+use crate::generate::{Generate, Rng};
+use crate::point::Point2;
+use crate::solution::Solution;
+use crate::sparse_grid::SparseGrid;
+
+type Position = Point2<i32>;
+
+type Move = (char, i32);
+
+fn next_knot_position(head: Position, tail: Position) -> Position {
+    if head.chebyshev(tail) > 1 {
+        // not touching any more, so step toward the head (diagonally if need be)
+        tail + (head - tail).signum()
+    } else {
+        tail
+    }
+}
+
+// Draws the trail the tail has left with the knots on top, labelled like the
+// puzzle does: H for the head, then 1 to 9 (or just T for a two-knot rope).
+fn render_rope(visited_positions: &SparseGrid<char>, knots: &[Position]) -> String {
+    // the puzzle's up is +y, but rendering draws y going down
+    let flip = |p: Position| Point2::new(p.x, -p.y);
+    let mut picture = SparseGrid::new();
+    for (p, &c) in visited_positions.iter() {
+        picture.insert(flip(p), c);
+    }
+    for (i, &knot) in knots.iter().enumerate().rev() {
+        let label = match i {
+            0 => 'H',
+            _ if knots.len() == 2 => 'T',
+            _ => char::from_digit(i as u32, 36).unwrap(),
+        };
+        picture.insert(flip(knot), label);
+    }
+    picture.render(|c| *c.unwrap_or(&'.')).to_string()
+}
+
+fn simulate_rope(moves: &[Move], knot_count: u32, recorder: &mut Recorder) -> usize {
+    let mut visited_positions = SparseGrid::new();
+    let mut knots = Vec::<Position>::new();
+
+    let mut i = 0;
+    // initialize knot list
+    while i < knot_count {
+        knots.push(Point2::new(0, 0));
+        i += 1;
+    }
+
+    visited_positions.insert(knots[knots.len() - 1], 's'); // because we start there
+
+    for (direction, count) in moves {
+        let mut count = *count;
+        log::debug!("Move: {} {}", direction, count);
+
+        while count > 0 {
+            // move the head of the rope
+            match direction {
+                'L' => knots[0].x -= 1,
+                'R' => knots[0].x += 1,
+                'D' => knots[0].y -= 1,
+                'U' => knots[0].y += 1,
+                _ => panic!("Unrecognized direction {direction}"),
+            }
+            // then move the subsequent knots one at a time
+            let mut i = 1;
+            while i < knots.len() {
+                knots[i] = next_knot_position(knots[i - 1], knots[i]);
+                i += 1;
+            }
+            // and record the tail position
+            let tail = knots[knots.len() - 1];
+            if !visited_positions.contains(tail) {
+                visited_positions.insert(tail, '#');
+            }
+            log::trace!(
+                "Knots: {}",
+                knots
+                    .iter()
+                    .map(|k| format!("({}, {})", k.x, k.y))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+            recorder.frame(|| render_rope(&visited_positions, &knots));
+            count -= 1;
+        }
+    }
+    visited_positions.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Move>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Move>, AocError> {
+        let mut moves = Vec::new();
+        for (i, line) in input.split("\n").enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let parts: Vec<&str> = line.split(" ").collect();
+            let direction = match parts[0] {
+                "L" | "R" | "D" | "U" if parts.len() == 2 => parts[0].chars().next().unwrap(),
+                _ => {
+                    return Err(
+                        AocError::new("expected 'L', 'R', 'U' or 'D' and a count").at_line(i + 1)
+                    )
+                }
+            };
+            let count = parse_number::<i32>(parts[1]).map_err(|err| err.at_line(i + 1))?;
+            moves.push((direction, count));
+        }
+        Ok(moves)
+    }
+
+    fn part1(moves: &Vec<Move>) -> usize {
+        simulate_rope(moves, 2, &mut Recorder::off())
+    }
+
+    fn part2(moves: &Vec<Move>) -> usize {
+        simulate_rope(moves, 10, &mut Recorder::off())
+    }
+}
+
+impl Animated for Day09 {
+    fn animate(moves: &Vec<Move>, part: u8, recorder: &mut Recorder) {
+        simulate_rope(moves, if part == 1 { 2 } else { 10 }, recorder);
+    }
+}
+
+impl Generate for Day09 {
+    // `size` moves of up to 20 steps each
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&["L", "R", "U", "D"]),
+                    rng.between(1, 20)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day09,
+        example: "09/example.txt" { part1 = 13, part2 = 1 },
+        larger: "09/larger.txt" { part1 = 88, part2 = 36 },
+    }
+
+    // Plain tuples and a HashSet, pulling each knot after the one in front of
+    // it one step at a time.
+    fn naive_tail_visits(input: &str, knot_count: usize) -> usize {
+        let mut knots = vec![(0i32, 0i32); knot_count];
+        let mut visited = HashSet::from([(0, 0)]);
+        for line in input.lines() {
+            let (direction, count) = line.split_once(' ').unwrap();
+            let (dx, dy) = match direction {
+                "L" => (-1, 0),
+                "R" => (1, 0),
+                "D" => (0, -1),
+                _ => (0, 1),
+            };
+            for _ in 0..count.parse::<i32>().unwrap() {
+                knots[0] = (knots[0].0 + dx, knots[0].1 + dy);
+                for i in 1..knot_count {
+                    let (hx, hy) = knots[i - 1];
+                    let (tx, ty) = knots[i];
+                    if (hx - tx).abs() > 1 || (hy - ty).abs() > 1 {
+                        knots[i] = (tx + (hx - tx).signum(), ty + (hy - ty).signum());
+                    }
+                }
+                visited.insert(knots[knot_count - 1]);
+            }
+        }
+        visited.len()
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day09>(20, 200) {
+            let moves = Day09::parse(&input).unwrap();
+            assert_eq!(
+                Day09::part1(&moves),
+                naive_tail_visits(&input, 2),
+                "seed {seed}"
+            );
+            assert_eq!(
+                Day09::part2(&moves),
+                naive_tail_visits(&input, 10),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_render_rope() {
+        let mut recorder = Recorder::new();
+        simulate_rope(&[('R', 2), ('U', 1)], 2, &mut recorder);
+        assert_eq!(recorder.frames().len(), 3);
+        assert_eq!(recorder.frames()[0], "TH");
+        assert_eq!(recorder.frames()[2], "..H\nsT.");
+    }
+
+    #[test]
+    fn test_next_knot_position() {
+        // up
+        let head: Position = Point2::new(0, 2);
+        let tail: Position = Point2::new(0, 0);
+        assert_eq!(next_knot_position(head, tail), Point2::new(0, 1));
+
+        // down
+        let head: Position = Point2::new(0, -3);
+        let tail: Position = Point2::new(0, -1);
+        assert_eq!(next_knot_position(head, tail), Point2::new(0, -2));
+
+        // right
+        let head: Position = Point2::new(0, 0);
+        let tail: Position = Point2::new(-3, 0);
+        assert_eq!(next_knot_position(head, tail), Point2::new(-2, 0));
+
+        // left
+        let head: Position = Point2::new(3, 0);
+        let tail: Position = Point2::new(5, 0);
+        assert_eq!(next_knot_position(head, tail), Point2::new(4, 0));
+
+        // diagonal
+        let head: Position = Point2::new(2, -1);
+        let tail: Position = Point2::new(0, 0);
+        assert_eq!(next_knot_position(head, tail), Point2::new(1, -1));
+
+        // another diagonal
+        let head: Position = Point2::new(2, 3);
+        let tail: Position = Point2::new(1, 1);
+        assert_eq!(next_knot_position(head, tail), Point2::new(2, 2));
+
+        // another diagonal
+        let head: Position = Point2::new(4, 1);
+        let tail: Position = Point2::new(3, 0);
+        assert_eq!(next_knot_position(head, tail), Point2::new(3, 0));
+
+        // another diagonal
+        let head: Position = Point2::new(4, 2);
+        let tail: Position = Point2::new(3, 0);
+        assert_eq!(next_knot_position(head, tail), Point2::new(4, 1));
+
+        // another diagonal
+        let head: Position = Point2::new(2, -3);
+        let tail: Position = Point2::new(0, -1);
+        assert_eq!(next_knot_position(head, tail), Point2::new(1, -2));
+    }
+}
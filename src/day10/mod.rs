@@ -0,0 +1,244 @@
+use crate::error::{parse_number, AocError};
+use crate::frames::{Animated, Recorder};
+use crate::generate::{Generate, Rng};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn evaluate_registers(cycle_count: i32, register_value: i32, signal_strengths: &mut Vec<i32>) {
+    if (cycle_count - 20) % 40 == 0 {
+        signal_strengths.push(register_value * cycle_count);
+    }
+}
+
+fn sum_signal_strengths(program: &[Instruction]) -> i32 {
+    let mut register_value = 1;
+    let mut cycle_count = 0;
+    let mut signal_strengths: Vec<i32> = Vec::new();
+
+    for instruction in program {
+        match instruction {
+            Instruction::Noop => {
+                cycle_count += 1;
+                evaluate_registers(cycle_count, register_value, &mut signal_strengths);
+            }
+            Instruction::Addx(value) => {
+                cycle_count += 1;
+                evaluate_registers(cycle_count, register_value, &mut signal_strengths);
+                cycle_count += 1;
+                evaluate_registers(cycle_count, register_value, &mut signal_strengths);
+                register_value += value;
+            }
+        }
+    }
+
+    signal_strengths.iter().sum::<i32>()
+}
+
+fn draw_pixel(cycle_count: usize, register_value: i32) -> char {
+    let col = (cycle_count - 1) % 40;
+    log::trace!(
+        "cycle: {}, col: {}, register: {}",
+        cycle_count,
+        col,
+        register_value
+    );
+
+    let sprite_center = register_value % 40;
+    let sprite_left = sprite_center - 1;
+    let sprite_right = sprite_center + 1;
+
+    if (col as i32) == sprite_center || (col as i32) == sprite_left || (col as i32) == sprite_right
+    {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+fn draw_screen(
+    cycle_count: usize,
+    register_value: i32,
+    screen: &mut Grid<char>,
+    recorder: &mut Recorder,
+) {
+    let row = (cycle_count - 1) / 40;
+    let col = (cycle_count - 1) % 40;
+
+    assert!(row < screen.rows());
+    assert!(col < screen.cols());
+
+    screen[(row, col)] = draw_pixel(cycle_count, register_value);
+    log::trace!("\n{}\n", screen.render(|&px| px));
+    // pixels the beam hasn't reached yet are still '\0'
+    recorder.frame(|| {
+        screen
+            .render(|&px| if px == '\0' { ' ' } else { px })
+            .to_string()
+    });
+}
+
+fn run_program(program: &[Instruction], recorder: &mut Recorder) -> String {
+    let mut cycle_count = 0;
+    let mut register_value = 1;
+    let mut screen: Grid<char> = Grid::new(6, 40);
+
+    for instruction in program {
+        match instruction {
+            Instruction::Noop => {
+                cycle_count += 1;
+                draw_screen(cycle_count, register_value, &mut screen, recorder);
+            }
+            Instruction::Addx(value) => {
+                cycle_count += 1;
+                draw_screen(cycle_count, register_value, &mut screen, recorder);
+                cycle_count += 1;
+                draw_screen(cycle_count, register_value, &mut screen, recorder);
+                register_value += value;
+            }
+        }
+    }
+
+    screen.render(|&px| px).to_string()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+        let mut program = Vec::new();
+        for (i, line) in input.split("\n").enumerate() {
+            let parts: Vec<&str> = line.split(" ").collect();
+            match parts[..] {
+                ["noop"] => program.push(Instruction::Noop),
+                [""] => continue,
+                ["addx", value] => program.push(Instruction::Addx(
+                    parse_number::<i32>(value).map_err(|err| err.at_line(i + 1))?,
+                )),
+                _ => {
+                    return Err(
+                        AocError::new(format!("unknown instruction '{}'", line)).at_line(i + 1)
+                    )
+                }
+            }
+        }
+        Ok(program)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> i32 {
+        sum_signal_strengths(program)
+    }
+
+    fn part2(program: &Vec<Instruction>) -> String {
+        run_program(program, &mut Recorder::off())
+    }
+}
+
+impl Animated for Day10 {
+    // only part 2 draws anything
+    fn animate(program: &Vec<Instruction>, part: u8, recorder: &mut Recorder) {
+        if part == 2 {
+            run_program(program, recorder);
+        }
+    }
+}
+
+impl Generate for Day10 {
+    // The screen is always 240 cycles, so `size` doesn't matter here. The
+    // register stays where the sprite can be seen without wrapping round.
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        let mut program = String::new();
+        let mut cycles = 0;
+        let mut register: i64 = 1;
+        while cycles < 240 {
+            if cycles == 239 || rng.one_in(3) {
+                program.push_str("noop\n");
+                cycles += 1;
+            } else {
+                let target = rng.between((register - 10).max(0), (register + 10).min(38));
+                program.push_str(&format!("addx {}\n", target - register));
+                register = target;
+                cycles += 2;
+            }
+        }
+        program
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    const SCREEN: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+    crate::example_tests! {
+        Day10,
+        example: "10/example.txt" { part1 = 13140, part2 = SCREEN },
+    }
+
+    // the register's value during every cycle, in order
+    fn naive_registers(input: &str) -> Vec<i32> {
+        let mut register = 1;
+        let mut registers = Vec::new();
+        for line in input.lines() {
+            registers.push(register);
+            if let Some(value) = line.strip_prefix("addx ") {
+                registers.push(register);
+                register += value.parse::<i32>().unwrap();
+            }
+        }
+        registers
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day10>(20, 0) {
+            let program = Day10::parse(&input).unwrap();
+            let registers = naive_registers(&input);
+            let strength: i32 = [20, 60, 100, 140, 180, 220]
+                .iter()
+                .map(|&cycle| cycle as i32 * registers[cycle - 1])
+                .sum();
+            assert_eq!(Day10::part1(&program), strength, "seed {seed}");
+
+            let rows: Vec<String> = registers
+                .chunks(40)
+                .map(|row| {
+                    (0..40)
+                        .map(|col| {
+                            if (col - row[col as usize]).abs() <= 1 {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            assert_eq!(Day10::part2(&program), rows.join("\n"), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_draw_pixel() {
+        assert_eq!(draw_pixel(1, 0), '#');
+        assert_eq!(draw_pixel(3, 16), '.');
+        assert_eq!(draw_pixel(9, 8), '#');
+        assert_eq!(draw_pixel(10, 8), '#');
+    }
+}
@@ -0,0 +1,377 @@
+use std::{collections::VecDeque, rc::Rc};
+
+use crate::error::{parse_number, AocError};
+use crate::frames::{Animated, Recorder};
+use crate::generate::{Generate, Rng};
+use crate::parse::records;
+use crate::solution::{Solution, Unsolved};
+
+#[derive(Clone)]
+pub struct Monkey {
+    name: usize,
+    items: VecDeque<usize>,
+    on_inspect: Rc<dyn Fn(usize) -> usize>,
+    test_divisor: usize,
+    throw_to_true: usize,
+    throw_to_false: usize,
+    inspect_count: usize,
+    lcm: Option<usize>,
+}
+
+impl Monkey {
+    fn new(
+        name: usize,
+        items: VecDeque<usize>,
+        on_inspect: Rc<dyn Fn(usize) -> usize>,
+        test_divisor: usize,
+        throw_to_true: usize,
+        throw_to_false: usize,
+        lcm: Option<usize>,
+    ) -> Monkey {
+        Monkey {
+            name,
+            items,
+            on_inspect,
+            test_divisor,
+            throw_to_true,
+            throw_to_false,
+            inspect_count: 0,
+            lcm,
+        }
+    }
+
+    fn set_lcm(&mut self, lcm: usize) {
+        self.lcm = Some(lcm);
+    }
+
+    fn inspect(&mut self) {
+        self.inspect_count += self.items.len();
+        let lcm = self.lcm.expect("LCM not found!");
+        self.items = self
+            .items
+            .iter()
+            .map(|item| (self.on_inspect)(*item) % lcm)
+            .collect();
+    }
+
+    fn throw(&mut self) -> usize {
+        self.items.pop_front().unwrap()
+    }
+
+    fn get_destination_monkey(&self, item: usize) -> usize {
+        if item.is_multiple_of(self.test_divisor) {
+            return self.throw_to_true;
+        }
+        self.throw_to_false
+    }
+
+    fn catch(&mut self, item: usize) {
+        self.items.push_back(item);
+    }
+}
+
+// Monkey 0:
+//   Starting items: 98, 97, 98, 55, 56, 72
+//   Operation: new = old * 13
+//   Test: divisible by 11
+//     If true: throw to monkey 4
+//     If false: throw to monkey 7
+
+fn make_operation(operation_line: &str) -> Result<Rc<dyn Fn(usize) -> usize>, AocError> {
+    let words: Vec<&str> = operation_line.split(" ").collect();
+    match words[..] {
+        ["new", "=", "old", "+", "old"] => Ok(Rc::new(move |b: usize| -> usize { b + b })),
+        ["new", "=", "old", "+", static_val] => {
+            let static_val = parse_number::<usize>(static_val)?;
+            Ok(Rc::new(move |b: usize| -> usize { static_val + b }))
+        }
+        ["new", "=", "old", "*", "old"] => Ok(Rc::new(move |b: usize| -> usize { b * b })),
+        ["new", "=", "old", "*", static_val] => {
+            let static_val = parse_number::<usize>(static_val)?;
+            Ok(Rc::new(move |b: usize| -> usize { b * static_val }))
+        }
+        _ => Err(AocError::new(
+            "expected 'new = old + N', 'new = old * N' or 'new = old * old'",
+        )),
+    }
+}
+
+// strips the label off one line of a monkey spec, e.g. "Test: divisible by "
+fn field<'a>(line: &'a str, label: &str, expected: &str) -> Result<&'a str, AocError> {
+    line.trim()
+        .strip_prefix(label)
+        .ok_or_else(|| AocError::new(format!("expected '{expected}'")))
+}
+
+fn parse_monkey(lines: &[&str]) -> Result<Monkey, (usize, AocError)> {
+    let at = |index: usize| move |err: AocError| (index, err);
+    if lines.len() != 6 {
+        return Err((0, AocError::new("expected six lines describing a monkey")));
+    }
+
+    let (name,): (usize,) =
+        crate::scan!(lines[0].trim(), "Monkey {}:", "Monkey N:").map_err(at(0))?;
+
+    let starting_items = field(lines[1], "Starting items: ", "Starting items: N, N, ...")
+        .map_err(at(1))?
+        .split(", ")
+        .map(parse_number::<usize>)
+        .collect::<Result<VecDeque<usize>, AocError>>()
+        .map_err(at(1))?;
+    log::debug!("Monkey {name}: {starting_items:?}");
+
+    let operation = field(lines[2], "Operation: ", "Operation: new = ...")
+        .and_then(make_operation)
+        .map_err(at(2))?;
+    let (test_divisor,): (usize,) = crate::scan!(
+        lines[3].trim(),
+        "Test: divisible by {}",
+        "Test: divisible by N"
+    )
+    .map_err(at(3))?;
+    if test_divisor == 0 {
+        return Err((3, AocError::new("nothing is divisible by 0")));
+    }
+    let (throw_to_true,): (usize,) = crate::scan!(
+        lines[4].trim(),
+        "If true: throw to monkey {}",
+        "If true: throw to monkey N"
+    )
+    .map_err(at(4))?;
+    let (throw_to_false,): (usize,) = crate::scan!(
+        lines[5].trim(),
+        "If false: throw to monkey {}",
+        "If false: throw to monkey N"
+    )
+    .map_err(at(5))?;
+
+    let lcm = None::<usize>;
+    Ok(Monkey::new(
+        name,
+        starting_items,
+        operation,
+        test_divisor,
+        throw_to_true,
+        throw_to_false,
+        lcm,
+    ))
+}
+
+fn parse_monkeys(contents: &str) -> Result<Vec<Monkey>, AocError> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut first_lines: Vec<usize> = Vec::new();
+    for monkey_spec in records(contents) {
+        let monkey = parse_monkey(&monkey_spec.lines)
+            .map_err(|(index, err)| err.at_line(monkey_spec.first_line + index))?;
+        monkeys.push(monkey);
+        first_lines.push(monkey_spec.first_line);
+    }
+    // monkey business multiplies the two busiest monkeys' counts
+    if monkeys.len() < 2 {
+        return Err(AocError::new(format!(
+            "expected at least two monkeys, found {}",
+            monkeys.len()
+        ))
+        .at_line(first_lines.first().copied().unwrap_or(1)));
+    }
+    for (monkey, first_line) in monkeys.iter().zip(first_lines) {
+        for (index, target) in [(4, monkey.throw_to_true), (5, monkey.throw_to_false)] {
+            if target >= monkeys.len() {
+                return Err(AocError::new(format!("there is no monkey {target}"))
+                    .at_line(first_line + index));
+            }
+        }
+    }
+    let lcm = monkeys.iter().map(|monkey| monkey.test_divisor).product();
+    for monkey in monkeys.iter_mut() {
+        monkey.set_lcm(lcm);
+    }
+    Ok(monkeys)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Output1 = Unsolved;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, AocError> {
+        parse_monkeys(input)
+    }
+
+    fn part1(_monkeys: &Vec<Monkey>) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(monkeys: &Vec<Monkey>) -> usize {
+        monkey_business(monkeys, &mut Recorder::off())
+    }
+}
+
+impl Animated for Day11 {
+    // part 1 isn't solved yet, so there's nothing to watch
+    fn animate(monkeys: &Vec<Monkey>, part: u8, recorder: &mut Recorder) {
+        if part == 2 {
+            monkey_business(monkeys, recorder);
+        }
+    }
+}
+
+// what every monkey is holding after a round, and how much inspecting it's done
+fn render_round(round: usize, monkeys: &[Monkey]) -> String {
+    let mut lines = vec![format!("Round {round}:")];
+    for monkey in monkeys {
+        lines.push(format!(
+            "Monkey {} ({} inspected): {:?}",
+            monkey.name, monkey.inspect_count, monkey.items
+        ));
+    }
+    lines.join("\n")
+}
+
+fn monkey_business(monkeys: &[Monkey], recorder: &mut Recorder) -> usize {
+    let mut monkeys = monkeys.to_vec();
+    for round in 1..10001 {
+        log::trace!("Round {}:", round);
+        for i in 0..monkeys.len() {
+            monkeys[i].inspect();
+            while !monkeys[i].items.is_empty() {
+                let thrown_item = monkeys[i].throw();
+                let dest = monkeys[i].get_destination_monkey(thrown_item);
+                monkeys[dest].catch(thrown_item);
+            }
+        }
+        for monkey in monkeys.iter() {
+            log::trace!("Monkey {}: {:?}", monkey.name, monkey.items);
+        }
+        recorder.frame(|| render_round(round, &monkeys));
+    }
+    let mut inspect_counts = monkeys
+        .iter()
+        .map(|monk| monk.inspect_count)
+        .collect::<Vec<usize>>();
+    inspect_counts.sort();
+    // parse checked there are at least two monkeys
+    inspect_counts.pop().unwrap() * inspect_counts.pop().unwrap()
+}
+
+impl Generate for Day11 {
+    // Between two and nine monkeys, each testing a different prime, like the
+    // real input does. A monkey never throws to itself.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        let count = size.clamp(2, primes.len());
+        let mut monkeys = Vec::new();
+        for (name, divisor) in primes.iter().take(count).enumerate() {
+            let items: Vec<String> = (0..rng.between(1, 6))
+                .map(|_| rng.between(50, 99).to_string())
+                .collect();
+            let operation = match rng.below(5) {
+                0 => "old * old".to_string(),
+                1 | 2 => format!("old + {}", rng.between(1, 9)),
+                _ => format!("old * {}", rng.between(2, 19)),
+            };
+            let mut target = || (name + 1 + rng.below(count - 1)) % count;
+            let (if_true, if_false) = (target(), target());
+            monkeys.push(format!(
+                "Monkey {name}:
+  Starting items: {}
+  Operation: new = {operation}
+  Test: divisible by {divisor}
+    If true: throw to monkey {if_true}
+    If false: throw to monkey {if_false}",
+                items.join(", ")
+            ));
+        }
+        monkeys.join("\n\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day11,
+        example: "11/example.txt" { part2 = 2713310158usize },
+    }
+
+    // Keeps each item as its remainder by every monkey's divisor rather than
+    // one number modulo their product.
+    fn naive_monkey_business(monkeys: &[Monkey]) -> usize {
+        let divisors: Vec<usize> = monkeys.iter().map(|m| m.test_divisor).collect();
+        let mut holding: Vec<Vec<Vec<usize>>> = monkeys
+            .iter()
+            .map(|m| {
+                m.items
+                    .iter()
+                    .map(|&item| divisors.iter().map(|d| item % d).collect())
+                    .collect()
+            })
+            .collect();
+        let mut inspected = vec![0; monkeys.len()];
+        for _ in 0..10000 {
+            for (i, monkey) in monkeys.iter().enumerate() {
+                for item in std::mem::take(&mut holding[i]) {
+                    inspected[i] += 1;
+                    let item: Vec<usize> = item
+                        .iter()
+                        .zip(&divisors)
+                        .map(|(r, d)| (monkey.on_inspect)(*r) % d)
+                        .collect();
+                    let dest = if item[i] == 0 {
+                        monkey.throw_to_true
+                    } else {
+                        monkey.throw_to_false
+                    };
+                    holding[dest].push(item);
+                }
+            }
+        }
+        inspected.sort();
+        inspected.iter().rev().take(2).product()
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let monkey = |name: usize, divisor: usize| {
+            format!(
+                "Monkey {name}:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by {divisor}
+    If true: throw to monkey 1
+    If false: throw to monkey 0
+"
+            )
+        };
+        let error = Day11::parse("").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1: expected at least two monkeys, found 0"
+        );
+        let error = Day11::parse(&monkey(0, 23)).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1: expected at least two monkeys, found 1"
+        );
+        let input = monkey(0, 23) + "\n" + &monkey(1, 0);
+        let error = Day11::parse(&input).err().unwrap();
+        assert_eq!(error.to_string(), "line 11: nothing is divisible by 0");
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day11>(5, 6) {
+            let monkeys = Day11::parse(&input).unwrap();
+            assert_eq!(
+                Day11::part2(&monkeys),
+                naive_monkey_business(&monkeys),
+                "seed {seed}"
+            );
+        }
+    }
+}
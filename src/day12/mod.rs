@@ -0,0 +1,165 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::point::Point2;
+use crate::search;
+use crate::solution::{Maybe, Solution};
+
+type Map = Grid<char>;
+
+fn parse_map(contents: &str) -> Result<Grid<char>, AocError> {
+    let map: Map = Grid::from_str(contents.trim(), |c| {
+        if c.is_ascii_lowercase() || c == 'S' || c == 'E' {
+            Ok(c)
+        } else {
+            Err(AocError::new(format!(
+                "expected a height from 'a' to 'z', 'S' or 'E', found '{c}'"
+            )))
+        }
+    })?;
+    for marker in ['S', 'E'] {
+        let mut found = map.positions().filter(|&pos| map[pos] == marker);
+        if found.next().is_none() {
+            return Err(AocError::new(format!("the map has no '{marker}'")));
+        }
+        if let Some((row, col)) = found.next() {
+            return Err(
+                AocError::new(format!("the map has more than one '{marker}'"))
+                    .at_line(row + 1)
+                    .at_column(col + 1),
+            );
+        }
+    }
+    Ok(map)
+}
+
+type Point = Point2<usize>;
+
+#[derive(Debug, PartialEq)]
+struct MapPoints {
+    start: Point,
+    end: Point,
+}
+
+fn find_start_and_end(map: &Map) -> MapPoints {
+    let find = |marker: char| match map.find(|c| *c == marker) {
+        Some(pos) => Point2::from_index(pos).unwrap(),
+        None => panic!("parse checked there's a '{marker}' on the map"),
+    };
+    MapPoints {
+        start: find('S'),
+        end: find('E'),
+    }
+}
+
+fn height(c: char) -> u8 {
+    match c {
+        'S' => b'a',
+        'E' => b'z',
+        c => c as u8,
+    }
+}
+
+// we can climb at most one step up, but can drop down as far as we like
+fn climbable_from(map: &Map, from: Point) -> impl Iterator<Item = Point> + '_ {
+    let limit = height(map[from.to_index().unwrap()]) + 1;
+    from.orthogonal()
+        .filter(move |step| match map.get(step.to_index().unwrap()) {
+            Some(&c) => height(c) <= limit,
+            None => false,
+        })
+}
+
+fn fewest_steps(map: &Map, starts: impl IntoIterator<Item = Point>, end: Point) -> Option<usize> {
+    let search = search::bfs(
+        starts,
+        |&point| climbable_from(map, point),
+        |&point| point == end,
+    );
+    let path = search.path()?;
+
+    // drawing the path means copying the whole map, so only when it'll be seen
+    if log::log_enabled!(log::Level::Debug) {
+        let mut drawn = map.clone();
+        for point in path {
+            drawn[point.to_index().unwrap()] = '.';
+        }
+        log::debug!("Found path:\n{}", drawn.render(|&c| c));
+    }
+    search.goal_cost()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+    type Output1 = Maybe<usize>;
+    type Output2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Map, AocError> {
+        parse_map(input)
+    }
+
+    fn part1(map: &Map) -> Maybe<usize> {
+        log::debug!("Map:\n{}", map.render(|&c| c));
+        let start_and_end = find_start_and_end(map);
+        log::debug!(
+            "Start: {:?} End: {:?}",
+            start_and_end.start,
+            start_and_end.end
+        );
+        let steps = fewest_steps(map, [start_and_end.start], start_and_end.end);
+        Maybe::new(steps, "no path")
+    }
+
+    fn part2(map: &Map) -> Maybe<usize> {
+        // every lowest square is a starting point, so search from all at once
+        let starts = map
+            .positions()
+            .filter(|&pos| height(map[pos]) == b'a')
+            .map(|pos| Point2::from_index(pos).unwrap());
+        let steps = fewest_steps(map, starts, find_start_and_end(map).end);
+        Maybe::new(steps, "no path")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day12,
+        example: "12/example.txt" { part1 = 31, part2 = 29 },
+    }
+
+    #[test]
+    fn test_part1() {
+        let test_contents = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+        let map = parse_map(test_contents).unwrap();
+        assert_eq!(
+            find_start_and_end(&map),
+            MapPoints {
+                start: Point2::new(0, 0),
+                end: Point2::new(5, 2),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // no way up is a fine map, it just has no answer
+        let map = parse_map("SazE").unwrap();
+        assert_eq!(Day12::part1(&map).to_string(), "no path");
+        assert_eq!(Day12::part2(&map).to_string(), "no path");
+        let error = parse_map("SabE\nabcS").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: the map has more than one 'S'"
+        );
+        let error = parse_map("Sab\nab!").unwrap_err();
+        assert!(error.message.starts_with("expected a height"));
+    }
+}
@@ -0,0 +1,354 @@
+use std::cmp::Ordering;
+use std::iter::zip;
+
+use crate::error::{parse_number, AocError};
+use crate::generate::{Generate, Rng};
+use crate::parse::records;
+use crate::solution::Solution;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Packet {
+    List(Box<Vec<Packet>>),
+    Int(i64),
+}
+
+impl std::cmp::PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::cmp::Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self {
+            Packet::Int(s) => {
+                match other {
+                    Packet::Int(o) => {
+                        // compare as ints
+                        s.cmp(o)
+                    }
+                    Packet::List(_) => {
+                        let self_as_list: Vec<Packet> = vec![Packet::Int(*s)];
+                        Packet::List(Box::new(self_as_list)).cmp(other)
+                    }
+                }
+            }
+            Packet::List(s) => {
+                match other {
+                    Packet::Int(o) => {
+                        // I am an List, the other is a Int
+                        let other_as_list = vec![Packet::Int(*o)];
+                        self.cmp(&Packet::List(Box::new(other_as_list)))
+                    }
+                    Packet::List(o) => {
+                        // we're both lists
+                        let iter = zip(s.iter(), o.iter());
+                        for (s1, o1) in iter {
+                            match s1.cmp(o1) {
+                                Ordering::Greater => return Ordering::Greater,
+                                Ordering::Less => return Ordering::Less,
+                                _ => continue,
+                            }
+                        }
+                        s.len().cmp(&o.len())
+                    }
+                }
+            }
+        }
+    }
+}
+
+// pushes the number we've been reading (if any) onto the current list; the
+// error points at the column the number started in
+fn push_number(
+    curr: &mut Vec<Packet>,
+    number_chars: &mut Vec<char>,
+    column: usize,
+) -> Result<(), AocError> {
+    if !number_chars.is_empty() {
+        let start = column - number_chars.len();
+        let number_text = String::from_iter(number_chars.drain(..));
+        let new_num = parse_number::<i64>(&number_text).map_err(|err| err.at_column(start))?;
+        curr.push(Packet::Int(new_num));
+    }
+    Ok(())
+}
+
+fn parse_line(line: &str) -> Result<Packet, AocError> {
+    let mut nesting: Vec<Vec<Packet>> = Vec::new();
+    let mut curr: Vec<Packet> = Vec::new();
+    let mut number_chars: Vec<char> = Vec::new();
+
+    let mut column = 0;
+    for char in line.chars() {
+        column += 1;
+        // a whole packet has been read, so the line should be over
+        if nesting.is_empty() && !curr.is_empty() {
+            return Err(
+                AocError::new(format!("unexpected '{char}' after the packet")).at_column(column),
+            );
+        }
+        match char {
+            '[' => {
+                if !number_chars.is_empty() {
+                    return Err(AocError::new("unexpected '['").at_column(column));
+                }
+                nesting.push(curr);
+                curr = Vec::new();
+            }
+            ']' => {
+                push_number(&mut curr, &mut number_chars, column)?;
+
+                let mut parent = match nesting.pop() {
+                    Some(parent) => parent,
+                    None => return Err(AocError::new("unexpected ']'").at_column(column)),
+                };
+                parent.push(Packet::List(Box::new(curr)));
+                curr = parent;
+            }
+            ',' => {
+                push_number(&mut curr, &mut number_chars, column)?;
+            }
+            _ => {
+                number_chars.push(char);
+            }
+        }
+    }
+    if !nesting.is_empty() {
+        return Err(AocError::new("unclosed '['").at_column(column + 1));
+    }
+    push_number(&mut curr, &mut number_chars, column + 1)?;
+    match curr.pop() {
+        Some(packet) => Ok(packet),
+        None => Err(AocError::new("expected a packet")),
+    }
+}
+
+fn sum_ordered_pair_indices(packets: &[Packet]) -> usize {
+    let mut index_sum = 0;
+    for (index, pair) in (1..).zip(packets.chunks(2)) {
+        let first = &pair[0];
+        let second = &pair[1];
+
+        if first < second {
+            index_sum += index;
+            log::trace!("Well ordered: index {}:\n{:?}\n{:?}", index, first, second);
+        }
+    }
+
+    index_sum
+}
+
+fn find_markers(packets: &[Packet]) -> usize {
+    let mut lines = packets.to_vec();
+
+    let divider_1: Packet =
+        Packet::List(Box::new(vec![Packet::List(Box::new(vec![Packet::Int(
+            2i64,
+        )]))]));
+    let divider_2: Packet =
+        Packet::List(Box::new(vec![Packet::List(Box::new(vec![Packet::Int(
+            6i64,
+        )]))]));
+    lines.push(divider_1);
+    lines.push(divider_2);
+    lines.sort();
+
+    let index_1 = lines
+        .iter()
+        .position(|p| {
+            p == &Packet::List(Box::new(vec![Packet::List(Box::new(vec![Packet::Int(
+                2i64,
+            )]))]))
+        })
+        .unwrap()
+        + 1;
+    let index_2 = lines
+        .iter()
+        .position(|p| {
+            p == &Packet::List(Box::new(vec![Packet::List(Box::new(vec![Packet::Int(
+                6i64,
+            )]))]))
+        })
+        .unwrap()
+        + 1;
+    log::trace!("Sorted:\n{:#?}", lines);
+    index_1 * index_2
+}
+
+// small numbers and short lists, so pairs often agree for a while before
+// they differ
+fn random_packet(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.between(0, 4))
+        .map(|_| {
+            if depth < 3 && rng.one_in(3) {
+                random_packet(rng, depth + 1)
+            } else {
+                rng.between(0, 10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Packet>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Packet>, AocError> {
+        let mut packets = Vec::new();
+        for pair in records(input) {
+            if pair.lines.len() != 2 {
+                return Err(
+                    AocError::new("expected packets to come in pairs").at_line(pair.last_line())
+                );
+            }
+            for (line_number, line) in pair.numbered() {
+                packets.push(parse_line(line.trim()).map_err(|err| err.at_line(line_number))?);
+            }
+        }
+        Ok(packets)
+    }
+
+    fn part1(packets: &Vec<Packet>) -> usize {
+        sum_ordered_pair_indices(packets)
+    }
+
+    fn part2(packets: &Vec<Packet>) -> usize {
+        find_markers(packets)
+    }
+}
+
+impl Generate for Day13 {
+    // `size` pairs of packets, none of them a copy of a divider packet
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut packet = || loop {
+            let packet = random_packet(rng, 0);
+            if packet != "[[2]]" && packet != "[[6]]" {
+                break packet;
+            }
+        };
+        let pairs: Vec<String> = (0..size)
+            .map(|_| format!("{}\n{}", packet(), packet()))
+            .collect();
+        pairs.join("\n\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day13,
+        example: "13/example.txt" { part1 = 13, part2 = 140 },
+    }
+
+    #[derive(Clone, Copy)]
+    enum Token {
+        Open,
+        Close,
+        Int(i64),
+    }
+
+    fn tokens(line: &str) -> VecDeque<Token> {
+        let mut tokens = VecDeque::new();
+        let mut number = String::new();
+        for c in line.chars() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+            if !number.is_empty() {
+                tokens.push_back(Token::Int(number.parse().unwrap()));
+                number.clear();
+            }
+            match c {
+                '[' => tokens.push_back(Token::Open),
+                ']' => tokens.push_back(Token::Close),
+                _ => {}
+            }
+        }
+        tokens
+    }
+
+    // Compares the packets' text token by token, without building any lists:
+    // a number up against a list just gets brackets put round it.
+    fn naive_cmp(left: &str, right: &str) -> Ordering {
+        let (mut left, mut right) = (tokens(left), tokens(right));
+        loop {
+            match (left.front().copied(), right.front().copied()) {
+                (None, None) => return Ordering::Equal,
+                (Some(Token::Open), Some(Token::Open))
+                | (Some(Token::Close), Some(Token::Close)) => {
+                    left.pop_front();
+                    right.pop_front();
+                }
+                (Some(Token::Int(a)), Some(Token::Int(b))) => match a.cmp(&b) {
+                    Ordering::Equal => {
+                        left.pop_front();
+                        right.pop_front();
+                    }
+                    order => return order,
+                },
+                (Some(Token::Close), _) | (None, _) => return Ordering::Less,
+                (_, Some(Token::Close)) | (_, None) => return Ordering::Greater,
+                (Some(Token::Int(a)), Some(Token::Open)) => {
+                    left.pop_front();
+                    left.push_front(Token::Close);
+                    left.push_front(Token::Int(a));
+                    left.push_front(Token::Open);
+                }
+                (Some(Token::Open), Some(Token::Int(b))) => {
+                    right.pop_front();
+                    right.push_front(Token::Close);
+                    right.push_front(Token::Int(b));
+                    right.push_front(Token::Open);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day13>(20, 50) {
+            let packets = Day13::parse(&input).unwrap();
+            let lines: Vec<&str> = input.lines().filter(|line| !line.is_empty()).collect();
+            let ordered: usize = lines
+                .chunks(2)
+                .enumerate()
+                .filter(|(_, pair)| naive_cmp(pair[0], pair[1]) == Ordering::Less)
+                .map(|(i, _)| i + 1)
+                .sum();
+            assert_eq!(Day13::part1(&packets), ordered, "seed {seed}");
+
+            // a divider's index is one more than everything sorted before it,
+            // and [[6]] also comes after [[2]]
+            let before = |divider: &str| {
+                lines
+                    .iter()
+                    .filter(|line| naive_cmp(line, divider) != Ordering::Greater)
+                    .count()
+            };
+            let markers = (before("[[2]]") + 1) * (before("[[6]]") + 2);
+            assert_eq!(Day13::part2(&packets), markers, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |line| parse_line(line).unwrap_err().to_string();
+        assert_eq!(error("[1][2]"), "column 4: unexpected '[' after the packet");
+        assert_eq!(error("[1],"), "column 4: unexpected ',' after the packet");
+        assert_eq!(error("[1,[2]"), "column 7: unclosed '['");
+        // columns count characters, not bytes
+        assert_eq!(error("[é,1]]"), "column 2: expected a number, found 'é'");
+        assert_eq!(error("]"), "column 1: unexpected ']'");
+    }
+}
@@ -0,0 +1,316 @@
+use crate::error::{parse_number, AocError};
+use crate::frames::{Animated, Recorder};
+use crate::generate::{Generate, Rng};
+use crate::point::Point2;
+use crate::solution::{Solution, Unsolved};
+use crate::sparse_grid::SparseGrid;
+
+type Point = Point2<i32>;
+
+type RockLine = Vec<Point>;
+
+#[derive(Clone)]
+enum CavePixel {
+    Rock,
+    Sand,
+}
+
+// only rock and sand are stored; everything else is air
+type Cave = SparseGrid<CavePixel>;
+
+fn render_pixel(px: Option<&CavePixel>) -> char {
+    match px {
+        Some(CavePixel::Rock) => '#',
+        Some(CavePixel::Sand) => 'o',
+        None => '.',
+    }
+}
+
+const SAND_START: Point = Point2::new(500, 0);
+
+// sand tries to fall straight down, then down and left, then down and right
+const FALLS: [Point; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+fn next_sand_point(cave: &Cave, curr: Point) -> Point {
+    for fall in FALLS {
+        if !cave.contains(curr + fall) {
+            return curr + fall;
+        }
+    }
+    curr
+}
+
+// Drops a unit of sand until it comes to rest, or reaches `lowest_y` with
+// nothing under it, and returns where it ended up. None if sand has already
+// piled up to the source, so there's nowhere for more to come from.
+fn add_sand(cave: &mut Cave, lowest_y: i32) -> Option<Point> {
+    if cave.contains(SAND_START) {
+        return None;
+    }
+    let mut sand = SAND_START;
+    while sand.y < lowest_y {
+        let next = next_sand_point(cave, sand);
+        if next == sand {
+            // it's come to rest
+            break;
+        }
+        sand = next;
+    }
+
+    cave.insert(sand, CavePixel::Sand);
+    Some(sand)
+}
+
+fn put_rocks_in_cave(cave: &mut Cave, lines: &[RockLine]) {
+    for line in lines {
+        for i in 0..line.len() {
+            let a = line[i];
+            // this point is definitely a rock
+            cave.insert(a, CavePixel::Rock);
+            // now let's draw the line with the next point if there is one
+            match line.get(i + 1) {
+                Some(&b) => {
+                    // draw the line segment between point a and point b
+                    // (rock lines are always horizontal or vertical)
+                    let step = (b - a).signum();
+                    let mut point = a;
+                    while point != b {
+                        point += step;
+                        cave.insert(point, CavePixel::Rock);
+                    }
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+fn parse_point(raw_point: &str) -> Result<Point, AocError> {
+    match raw_point.split_once(",") {
+        Some((x, y)) => Ok(Point2::new(parse_number(x)?, parse_number(y)?)),
+        None => Err(AocError::new(format!(
+            "expected 'X,Y', found '{raw_point}'"
+        ))),
+    }
+}
+
+fn parse_rock_line(line: &str) -> Result<RockLine, AocError> {
+    let points = line
+        .split(" -> ")
+        .map(parse_point)
+        .collect::<Result<RockLine, AocError>>()?;
+    // put_rocks_in_cave walks each segment one step at a time, which only
+    // ever gets from one end to the other along a straight row or column
+    for pair in points.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if a.x != b.x && a.y != b.y {
+            return Err(AocError::new(format!(
+                "rock from {},{} to {},{} is neither horizontal nor vertical",
+                a.x, a.y, b.x, b.y
+            )));
+        }
+    }
+    Ok(points)
+}
+
+fn parse_rock_lines(data: &str) -> Result<Vec<RockLine>, AocError> {
+    data.trim()
+        .split("\n")
+        .enumerate()
+        .map(|(i, line)| parse_rock_line(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+// Pours sand in until it starts falling past the lowest rock or blocks the
+// source, and returns how many units came to rest.
+fn fill_with_sand(rock_lines: &[RockLine], recorder: &mut Recorder) -> usize {
+    let mut cave = Cave::new();
+    put_rocks_in_cave(&mut cave, rock_lines);
+    let (_, max) = cave.bounds().expect("no rocks in the cave");
+
+    let mut sand_counter: usize = 0;
+    // once sand falls below the lowest rock it's falling forever
+    while let Some(sand) = add_sand(&mut cave, max.y + 1) {
+        if sand.y > max.y {
+            break;
+        }
+        sand_counter += 1;
+        recorder.frame(|| cave.render(render_pixel).to_string());
+    }
+
+    sand_counter
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<RockLine>;
+    type Output1 = usize;
+    type Output2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Vec<RockLine>, AocError> {
+        parse_rock_lines(input)
+    }
+
+    fn part1(rock_lines: &Vec<RockLine>) -> usize {
+        fill_with_sand(rock_lines, &mut Recorder::off())
+    }
+
+    fn part2(_rock_lines: &Vec<RockLine>) -> Unsolved {
+        Unsolved
+    }
+}
+
+impl Animated for Day14 {
+    // part 2 isn't solved yet, so there's nothing to watch
+    fn animate(rock_lines: &Vec<RockLine>, part: u8, recorder: &mut Recorder) {
+        if part == 1 {
+            fill_with_sand(rock_lines, recorder);
+        }
+    }
+}
+
+impl Generate for Day14 {
+    // `size` rock paths under the sand's source, each turning a corner a few
+    // times
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let mut point =
+                    Point2::new(rng.between(470, 530) as i32, rng.between(10, 60) as i32);
+                let mut points = vec![format!("{},{}", point.x, point.y)];
+                let mut horizontal = rng.one_in(2);
+                for _ in 0..rng.between(1, 4) {
+                    let step = rng.between(1, 8) as i32 * if rng.one_in(2) { -1 } else { 1 };
+                    if horizontal {
+                        point.x += step;
+                    } else {
+                        // stay well below the source
+                        point.y = (point.y + step).max(2);
+                    }
+                    horizontal = !horizontal;
+                    points.push(format!("{},{}", point.x, point.y));
+                }
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day14,
+        example: "14/example.txt" { part1 = 24 },
+    }
+
+    // Pours sand into a plain 2D array wide enough that anything reaching its
+    // edge has already fallen past the rocks.
+    fn naive_sand_count(input: &str) -> usize {
+        let paths: Vec<Vec<(usize, usize)>> = input
+            .lines()
+            .map(|line| {
+                line.split(" -> ")
+                    .map(|p| {
+                        let (x, y) = p.split_once(',').unwrap();
+                        (x.parse().unwrap(), y.parse().unwrap())
+                    })
+                    .collect()
+            })
+            .collect();
+        let max_y = paths.iter().flatten().map(|p| p.1).max().unwrap();
+        let width = 1000 + 2 * max_y;
+        let mut blocked = vec![vec![false; width]; max_y + 2];
+        for path in &paths {
+            for pair in path.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                for row in &mut blocked[a.1.min(b.1)..=a.1.max(b.1)] {
+                    row[a.0.min(b.0)..=a.0.max(b.0)].fill(true);
+                }
+            }
+            let (x, y) = path[0];
+            blocked[y][x] = true;
+        }
+
+        let mut count = 0;
+        loop {
+            let (mut x, mut y) = (500, 0);
+            loop {
+                if y > max_y {
+                    return count;
+                }
+                if !blocked[y + 1][x] {
+                    y += 1;
+                } else if !blocked[y + 1][x - 1] {
+                    y += 1;
+                    x -= 1;
+                } else if !blocked[y + 1][x + 1] {
+                    y += 1;
+                    x += 1;
+                } else {
+                    break;
+                }
+            }
+            blocked[y][x] = true;
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day14>(20, 15) {
+            let rock_lines = Day14::parse(&input).unwrap();
+            assert_eq!(
+                Day14::part1(&rock_lines),
+                naive_sand_count(&input),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_rock_lines() {
+        let test_input = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+        let result = parse_rock_lines(test_input).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].len(), 4);
+
+        let error = parse_rock_lines("498,4 -> 498,6\n498,4 -> 500,7").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: rock from 498,4 to 500,7 is neither horizontal nor vertical"
+        );
+    }
+
+    #[test]
+    fn test_sand() {
+        // with nothing to land on, sand stops at the lowest row it's allowed
+        assert_eq!(add_sand(&mut Cave::new(), 4), Some(Point2::new(500, 4)));
+
+        let mut cave = Cave::new();
+        put_rocks_in_cave(&mut cave, &parse_rock_lines("497,5 -> 503,5").unwrap());
+        assert_eq!(add_sand(&mut cave, 10), Some(Point2::new(500, 4)));
+        assert_eq!(add_sand(&mut cave, 10), Some(Point2::new(499, 4)));
+        assert_eq!(add_sand(&mut cave, 10), Some(Point2::new(501, 4)));
+        assert_eq!(add_sand(&mut cave, 10), Some(Point2::new(500, 3)));
+        let picture = cave.render(render_pixel).to_string();
+        assert_eq!(picture, "...o...\n..ooo..\n#######");
+
+        let mut recorder = Recorder::new();
+        let rock_lines = parse_rock_lines("497,5 -> 503,5").unwrap();
+        assert_eq!(fill_with_sand(&rock_lines, &mut recorder), 9);
+        assert_eq!(recorder.frames()[3], picture);
+
+        // a closed basin fills right up to the source, then nothing more fits
+        let rock_lines = parse_rock_lines("498,1 -> 498,3 -> 502,3 -> 502,1").unwrap();
+        assert_eq!(fill_with_sand(&rock_lines, &mut Recorder::off()), 3 * 2 + 1);
+        let mut cave = Cave::new();
+        put_rocks_in_cave(&mut cave, &[Vec::from([SAND_START])]);
+        assert_eq!(add_sand(&mut cave, 10), None);
+    }
+}
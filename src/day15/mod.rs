@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use crate::error::AocError;
+use crate::generate::{Generate, Rng};
+use crate::interval_set::{Interval, IntervalSet};
+use crate::solution::{Solution, Unsolved};
+
+type Point = (i64, i64);
+
+pub struct Sensor {
+    loc: Point,
+    beacon: Point,
+    distance: i64,
+}
+
+fn find_impossible_coords(y_val: i64, s: &Sensor) -> Option<Interval> {
+    // the sensor's diamond is this far either side of it at row y_val
+    let overlap_amount = s.distance - (s.loc.1 - y_val).abs();
+    if overlap_amount < 0 {
+        // no overlap
+        return None;
+    }
+    Some(Interval::new(
+        s.loc.0 - overlap_amount,
+        s.loc.0 + overlap_amount,
+    ))
+}
+
+fn get_distance(a: &Point, b: &Point) -> i64 {
+    let diff_x = (a.0 - b.0).abs();
+    let diff_y = (a.1 - b.1).abs();
+
+    diff_x + diff_y
+}
+
+fn parse_sensor(line: &str) -> Result<Sensor, AocError> {
+    let (sensor_x, sensor_y, beacon_x, beacon_y) = crate::scan!(
+        line,
+        "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+        "Sensor at x=N, y=N: closest beacon is at x=N, y=N"
+    )?;
+    let sensor_coords = (sensor_x, sensor_y);
+    let beacon_coords = (beacon_x, beacon_y);
+    let distance = get_distance(&sensor_coords, &beacon_coords);
+    Ok(Sensor {
+        loc: sensor_coords,
+        beacon: beacon_coords,
+        distance,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Sensor>, AocError> {
+    input
+        .trim()
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| parse_sensor(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+fn count_impossible_positions(sensors: &[Sensor], y_val: i64) -> i64 {
+    let impossible_coordinate_ranges: IntervalSet = sensors
+        .iter()
+        .filter_map(|sensor| find_impossible_coords(y_val, sensor))
+        .collect();
+    // the beacons we already know about are where a beacon can be
+    let known_beacons: HashSet<i64> = sensors
+        .iter()
+        .filter(|s| s.beacon.1 == y_val && impossible_coordinate_ranges.contains(s.beacon.0))
+        .map(|s| s.beacon.0)
+        .collect();
+    impossible_coordinate_ranges.covered() - known_beacons.len() as i64
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Output1 = i64;
+    type Output2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Vec<Sensor>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(sensors: &Vec<Sensor>) -> i64 {
+        const TESTED_Y_VAL: i64 = 2000000;
+        count_impossible_positions(sensors, TESTED_Y_VAL)
+    }
+
+    fn part2(_sensors: &Vec<Sensor>) -> Unsolved {
+        Unsolved
+    }
+}
+
+impl Generate for Day15 {
+    // `size` sensors scattered around the row part 1 asks about. Each one's
+    // beacon really is the closest, with no ties, as the puzzle promises.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const ROW: i64 = 2000000;
+        let random_point = |rng: &mut Rng| (rng.between(-60, 60), ROW + rng.between(-40, 40));
+        let beacons: Vec<Point> = (0..size.max(1)).map(|_| random_point(rng)).collect();
+        let mut lines = String::new();
+        let mut sensors = 0;
+        while sensors < size.max(1) {
+            let sensor = random_point(rng);
+            let mut distances: Vec<(i64, Point)> = beacons
+                .iter()
+                .map(|b| (get_distance(&sensor, b), *b))
+                .collect();
+            distances.sort();
+            let (distance, beacon) = distances[0];
+            let tied = distances
+                .get(1)
+                .is_some_and(|&(next, b)| next == distance && b != beacon);
+            if distance == 0 || tied {
+                continue;
+            }
+            lines.push_str(&format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            ));
+            sensors += 1;
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+    use crate::parse::ints;
+
+    // the example asks about row 10 rather than the real puzzle's row, so this
+    // can't go through example_tests!
+    #[test]
+    fn test_count_impossible_positions() {
+        let input = include_str!("../../examples/15/example.txt");
+        let sensors = Day15::parse(input).unwrap();
+        assert_eq!(count_impossible_positions(&sensors, 10), 26);
+    }
+
+    // tries every x along the row that any sensor could possibly reach
+    fn naive_impossible(input: &str, y: i64) -> i64 {
+        let sensors: Vec<Vec<i64>> = input.lines().map(|line| ints(line).unwrap()).collect();
+        let reach = |s: &Vec<i64>| (s[0] - s[2]).abs() + (s[1] - s[3]).abs();
+        let min_x = sensors.iter().map(|s| s[0] - reach(s)).min().unwrap();
+        let max_x = sensors.iter().map(|s| s[0] + reach(s)).max().unwrap();
+        (min_x..=max_x)
+            .filter(|&x| {
+                let covered = sensors
+                    .iter()
+                    .any(|s| (s[0] - x).abs() + (s[1] - y).abs() <= reach(s));
+                let beacon = sensors.iter().any(|s| s[2] == x && s[3] == y);
+                covered && !beacon
+            })
+            .count() as i64
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day15>(20, 10) {
+            let sensors = Day15::parse(&input).unwrap();
+            assert_eq!(
+                Day15::part1(&sensors),
+                naive_impossible(&input, 2000000),
+                "seed {seed}"
+            );
+            for y in [1999990, 2000017] {
+                assert_eq!(
+                    count_impossible_positions(&sensors, y),
+                    naive_impossible(&input, y),
+                    "seed {seed}, row {y}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+
+use crate::error::AocError;
+use crate::generate::{Generate, Rng};
+use crate::search;
+use crate::solution::{Solution, Unsolved};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Valve {
+    name: String,
+    connection_names: Vec<String>,
+    flow: usize,
+}
+
+fn parse_line(line: &str) -> Result<Valve, AocError> {
+    const EXPECTED: &str = "Valve XX has flow rate=N; tunnels lead to valves YY, ZZ";
+    let (name, flow, tunnels): (String, usize, String) =
+        crate::scan!(line, "Valve {} has flow rate={}; {}", EXPECTED)?;
+    // a valve with a single tunnel gets the singular
+    let connections = tunnels
+        .strip_prefix("tunnels lead to valves ")
+        .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+        .ok_or_else(|| AocError::new(format!("expected '{EXPECTED}'")))?;
+    let connection_names: Vec<String> = connections
+        .split(", ")
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>();
+
+    log::debug!("Valve: {}, {}, {:?}", name, flow, connection_names);
+    Ok(Valve {
+        name,
+        flow,
+        connection_names,
+    })
+}
+
+fn make_valve_map(input: &str) -> Result<HashMap<String, Valve>, AocError> {
+    let mut tunnels: HashMap<String, Valve> = HashMap::new();
+
+    // the valves in the order they're listed, for errors
+    let mut names = Vec::new();
+    for (i, line) in input.trim().split("\n").enumerate() {
+        let tunnel = parse_line(line.trim()).map_err(|err| err.at_line(i + 1))?;
+        names.push(tunnel.name.clone());
+        tunnels.insert(tunnel.name.clone(), tunnel);
+    }
+
+    // every tunnel has to lead somewhere, and we need somewhere to start
+    for (i, name) in names.iter().enumerate() {
+        let valve = &tunnels[name];
+        if let Some(missing) = valve
+            .connection_names
+            .iter()
+            .find(|name| !tunnels.contains_key(*name))
+        {
+            return Err(AocError::new(format!(
+                "valve {} has a tunnel to valve {missing}, which isn't listed",
+                valve.name
+            ))
+            .at_line(i + 1));
+        }
+    }
+    if !tunnels.contains_key("AA") {
+        return Err(AocError::new(format!(
+            "none of the {} valves is AA, where we start",
+            names.len()
+        )));
+    }
+    Ok(tunnels)
+}
+
+const MINUTES: usize = 30;
+
+// The valves worth opening, plus AA where we start, with how many minutes it
+// takes to walk between each pair of them.
+struct Network {
+    flows: Vec<usize>,
+    distances: Vec<Vec<usize>>,
+    start: usize,
+}
+
+fn make_network(map: &HashMap<String, Valve>) -> Network {
+    let mut names: Vec<&str> = map
+        .values()
+        .filter(|v| v.flow > 0 || v.name == "AA")
+        .map(|v| v.name.as_str())
+        .collect();
+    names.sort();
+    // opened valves are tracked as bits of a u64
+    assert!(names.len() <= 64, "too many valves with a flow");
+
+    let distances = names
+        .iter()
+        .map(|&from| {
+            let walk = search::bfs(
+                [from],
+                |&name| match map.get(name) {
+                    Some(v) => v.connection_names.iter().map(|c| c.as_str()).collect(),
+                    None => vec![],
+                },
+                |_| false,
+            );
+            names
+                .iter()
+                .map(|to| walk.cost(to).unwrap_or(usize::MAX))
+                .collect()
+        })
+        .collect();
+
+    Network {
+        flows: names.iter().map(|name| map[*name].flow).collect(),
+        distances,
+        start: names
+            .iter()
+            .position(|&name| name == "AA")
+            .expect("parse checked there's a valve AA"),
+    }
+}
+
+// Where we are, which valves are open, and the minute it is.
+type State = (usize, u64, usize);
+
+// Releasing the most pressure is the same as losing the least while valves
+// are still shut, which makes it a cheapest path: every minute costs the
+// flow of every valve that's closed during it. From each state we can walk
+// to a closed valve and open it, or stand still until the time runs out.
+fn most_pressure(network: &Network) -> usize {
+    let total_flow: usize = network.flows.iter().sum();
+    let next_states = |&(at, opened, minute): &State| {
+        let leaking: usize = (0..network.flows.len())
+            .filter(|&v| opened & 1 << v == 0)
+            .map(|v| network.flows[v])
+            .sum();
+        let mut next = vec![((at, opened, MINUTES), (MINUTES - minute) * leaking)];
+        for (v, &distance) in network.distances[at].iter().enumerate() {
+            let opened_at = minute.saturating_add(distance).saturating_add(1);
+            if opened & 1 << v == 0 && network.flows[v] > 0 && opened_at <= MINUTES {
+                next.push((
+                    (v, opened | 1 << v, opened_at),
+                    (opened_at - minute) * leaking,
+                ));
+            }
+        }
+        next
+    };
+    let search = search::dijkstra([(network.start, 0, 0)], next_states, |&(_, _, minute)| {
+        minute == MINUTES
+    });
+    total_flow * MINUTES - search.goal_cost().unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = HashMap<String, Valve>;
+    type Output1 = usize;
+    type Output2 = Unsolved;
+
+    fn parse(input: &str) -> Result<HashMap<String, Valve>, AocError> {
+        make_valve_map(input)
+    }
+
+    fn part1(valve_map: &HashMap<String, Valve>) -> usize {
+        most_pressure(&make_network(valve_map))
+    }
+
+    fn part2(_valve_map: &HashMap<String, Valve>) -> Unsolved {
+        Unsolved
+    }
+}
+
+impl Generate for Day16 {
+    // `size` valves (AA and at least one more) joined up so every valve can be
+    // reached, with at most eight of them worth opening
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.clamp(2, 26 * 26);
+        let mut names = vec!["AA".to_string()];
+        while names.len() < count {
+            let name: String = (0..2)
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let mut flows = vec![0; count];
+        for _ in 0..8.min(count - 1) {
+            flows[1 + rng.below(count - 1)] = rng.between(1, 25);
+        }
+
+        let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); count];
+        let mut join = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        // a random tree first so nothing's cut off, then a few shortcuts
+        for valve in 1..count {
+            join(valve, rng.below(valve));
+        }
+        for _ in 0..count / 3 {
+            join(rng.below(count), rng.below(count));
+        }
+
+        let mut order: Vec<usize> = (0..count).collect();
+        rng.shuffle(&mut order);
+        order
+            .into_iter()
+            .map(|valve| {
+                let leads_to: Vec<&str> =
+                    tunnels[valve].iter().map(|&v| names[v].as_str()).collect();
+                let tunnels = match leads_to.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                format!(
+                    "Valve {} has flow rate={}; {} {}\n",
+                    names[valve],
+                    flows[valve],
+                    tunnels,
+                    leads_to.join(", ")
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day16,
+        example: "16/example.txt" { part1 = 1651 },
+    }
+
+    // Tries every order of opening the valves that have a flow, walking
+    // between them by shortest paths from Floyd-Warshall.
+    fn naive_most_pressure(valve_map: &HashMap<String, Valve>) -> usize {
+        let names: Vec<&String> = valve_map.keys().collect();
+        let index = |name: &str| names.iter().position(|n| *n == name).unwrap();
+        let n = names.len();
+        let mut distance = vec![vec![usize::MAX / 4; n]; n];
+        for (i, name) in names.iter().enumerate() {
+            distance[i][i] = 0;
+            for next in &valve_map[*name].connection_names {
+                distance[i][index(next)] = 1;
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    distance[i][j] = distance[i][j].min(distance[i][k] + distance[k][j]);
+                }
+            }
+        }
+        let useful: Vec<usize> = (0..n).filter(|&i| valve_map[names[i]].flow > 0).collect();
+
+        fn visit(
+            at: usize,
+            minutes_left: usize,
+            closed: &mut Vec<usize>,
+            distance: &[Vec<usize>],
+            flow: &dyn Fn(usize) -> usize,
+        ) -> usize {
+            let mut best = 0;
+            for i in 0..closed.len() {
+                let next = closed[i];
+                let cost = distance[at][next] + 1;
+                if cost >= minutes_left {
+                    continue;
+                }
+                closed.swap_remove(i);
+                let released = (minutes_left - cost) * flow(next)
+                    + visit(next, minutes_left - cost, closed, distance, flow);
+                closed.push(next);
+                let last = closed.len() - 1;
+                closed.swap(i, last);
+                best = best.max(released);
+            }
+            best
+        }
+        let flow = |i: usize| valve_map[names[i]].flow;
+        visit(index("AA"), MINUTES, &mut useful.clone(), &distance, &flow)
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day16>(10, 15) {
+            let valve_map = Day16::parse(&input).unwrap();
+            assert_eq!(
+                Day16::part1(&valve_map),
+                naive_most_pressure(&valve_map),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB";
+        assert_eq!(
+            parse_line(line).unwrap(),
+            Valve {
+                name: String::from("AA"),
+                connection_names: vec![String::from("DD"), String::from("II"), String::from("BB")],
+                flow: 0,
+            }
+        )
+    }
+
+    #[test]
+    fn test_valve_map() {
+        let test_input = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+        let map = make_valve_map(test_input).unwrap();
+        assert_eq!(map.len(), 10);
+        assert_eq!(
+            map.get("EE").unwrap(),
+            &parse_line("Valve EE has flow rate=3; tunnels lead to valves FF, DD").unwrap()
+        );
+
+        let error = make_valve_map(
+            "Valve BB has flow rate=13; tunnel leads to valve CC
+Valve CC has flow rate=2; tunnel leads to valve BB",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "none of the 2 valves is AA, where we start"
+        );
+        let error = make_valve_map(
+            "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=13; tunnels lead to valves AA, CC",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: valve BB has a tunnel to valve CC, which isn't listed"
+        );
+    }
+}
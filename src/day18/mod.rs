@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_number, AocError};
+use crate::generate::{Generate, Rng};
+use crate::point::Point3;
+use crate::solution::{Solution, Unsolved};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Cube {
+    pos: Point3<i32>,
+    faces: [bool; 6],
+}
+
+// which way each face points; opposite faces add up to 5
+const FACES: [Point3<i32>; 6] = [
+    Point3::new(1, 0, 0),
+    Point3::new(0, 1, 0),
+    Point3::new(0, 0, 1),
+    Point3::new(0, 0, -1),
+    Point3::new(0, -1, 0),
+    Point3::new(-1, 0, 0),
+];
+
+fn get_opposite_face(s: usize) -> usize {
+    match s {
+        0 => 5,
+        1 => 4,
+        2 => 3,
+        3 => 2,
+        4 => 1,
+        5 => 0,
+        _ => panic!("Invalid face {}", s),
+    }
+}
+
+fn parse_line(line: &str) -> Result<Cube, AocError> {
+    let coords = line
+        .trim()
+        .split(",")
+        .map(parse_number::<i32>)
+        .collect::<Result<Vec<i32>, AocError>>()?;
+    if coords.len() != 3 {
+        return Err(AocError::new("expected 'X,Y,Z'"));
+    }
+    Ok(Cube {
+        pos: Point3::new(coords[0], coords[1], coords[2]),
+        faces: [true, true, true, true, true, true],
+    })
+}
+
+fn get_adjacent_air_cube(cubes: &mut [Cube], cube_index: usize, face: usize) -> Option<&Cube> {
+    let neighbour = cubes[cube_index].pos + FACES[face];
+    let adjacent_cube_index = cubes.iter().position(|c| c.pos == neighbour);
+
+    match adjacent_cube_index {
+        Some(i) => {
+            cubes[i].faces[get_opposite_face(face)] = false;
+            cubes[cube_index].faces[face] = false;
+            Some(&cubes[i])
+        }
+        None => None,
+    }
+}
+
+fn score_cube(cube: &Cube) -> usize {
+    cube.faces
+        .iter()
+        .filter(|f| **f)
+        .collect::<Vec<&bool>>()
+        .len()
+}
+
+fn get_surface_area(cubes: &[Cube]) -> usize {
+    let mut count = 0;
+    for cube in cubes.iter() {
+        count += score_cube(cube);
+    }
+    count
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Cube>;
+    type Output1 = usize;
+    type Output2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Vec<Cube>, AocError> {
+        let mut cubes: Vec<Cube> = Vec::new();
+
+        for (i, line) in input.trim().split("\n").enumerate() {
+            cubes.push(parse_line(line).map_err(|err| err.at_line(i + 1))?);
+        }
+        Ok(cubes)
+    }
+
+    fn part1(cubes: &Vec<Cube>) -> usize {
+        let mut cubes = cubes.clone();
+
+        let mut i = 0;
+        while i < cubes.len() {
+            let mut j = 0;
+            // check each face
+            while j < 6 {
+                // if it's (so far) uncovered
+                if cubes[i].faces[j] {
+                    get_adjacent_air_cube(&mut cubes, i, j);
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        get_surface_area(&cubes)
+    }
+
+    fn part2(_cubes: &Vec<Cube>) -> Unsolved {
+        Unsolved
+    }
+}
+
+impl Generate for Day18 {
+    // `size` different cubes packed into a box about twice their volume, so
+    // plenty of them touch
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.max(1);
+        let side = ((count * 2) as f64).cbrt().ceil() as i64 + 1;
+        let mut cubes = HashSet::new();
+        let mut lines = String::new();
+        while cubes.len() < count {
+            let cube = (
+                rng.between(1, side),
+                rng.between(1, side),
+                rng.between(1, side),
+            );
+            if cubes.insert(cube) {
+                lines.push_str(&format!("{},{},{}\n", cube.0, cube.1, cube.2));
+            }
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::seeded_inputs;
+
+    crate::example_tests! {
+        Day18,
+        example: "18/example.txt" { part1 = 64 },
+    }
+
+    // every face whose neighbouring spot has no cube in it
+    fn naive_surface_area(input: &str) -> usize {
+        let cubes: HashSet<Vec<i32>> = input
+            .lines()
+            .map(|line| line.split(',').map(|n| n.parse().unwrap()).collect())
+            .collect();
+        let mut area = 0;
+        for cube in &cubes {
+            for axis in 0..3 {
+                for step in [-1, 1] {
+                    let mut neighbour = cube.clone();
+                    neighbour[axis] += step;
+                    if !cubes.contains(&neighbour) {
+                        area += 1;
+                    }
+                }
+            }
+        }
+        area
+    }
+
+    #[test]
+    fn test_against_naive() {
+        for (seed, input) in seeded_inputs::<Day18>(20, 100) {
+            let cubes = Day18::parse(&input).unwrap();
+            assert_eq!(
+                Day18::part1(&cubes),
+                naive_surface_area(&input),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_adjacent_cube() {
+        let mut cubes = vec![parse_line("1,1,1").unwrap(), parse_line("2,1,1").unwrap()];
+        let adj = get_adjacent_air_cube(&mut cubes, 0, 0);
+        assert_eq!(adj.unwrap().pos.x, 2);
+        assert_eq!(adj.unwrap().faces, [true, true, true, true, true, false]);
+        assert_eq!(
+            cubes.first().unwrap().faces,
+            [false, true, true, true, true, true]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// What went wrong while reading a puzzle input, and where. Line and column
+// are 1-based. Parsers that only see a single line report the problem
+// without a line number, and whoever split the input up adds it with
+// `at_line` on the way out.
+#[derive(Debug, PartialEq)]
+pub struct AocError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> AocError {
+        AocError {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> AocError {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> AocError {
+        self.column = Some(column);
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for AocError {}
+
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, AocError> {
+    text.parse::<T>()
+        .map_err(|_| AocError::new(format!("expected a number, found '{text}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = AocError::new("expected 'move N from A to B'");
+        assert_eq!(error.to_string(), "expected 'move N from A to B'");
+        let error = error.at_line(42);
+        assert_eq!(error.to_string(), "line 42: expected 'move N from A to B'");
+        let error = error.at_column(7);
+        assert_eq!(
+            error.to_string(),
+            "line 42, column 7: expected 'move N from A to B'"
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number::<i32>("-12"), Ok(-12));
+        assert_eq!(
+            parse_number::<u32>("twelve").unwrap_err().message,
+            "expected a number, found 'twelve'"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::AocError;
+use crate::solution::Solution;
+
+// Collects rendered frames of a simulation so they can be replayed later.
+// Frames are rendered lazily, so a simulation can always report its frames and
+// only pays for drawing them when something is actually recording.
+pub struct Recorder {
+    frames: Vec<String>,
+    recording: bool,
+    every: usize,
+    offered: usize,
+}
+
+impl Recorder {
+    pub fn new() -> Recorder {
+        Recorder::every(1)
+    }
+
+    // Keeps only one frame in `n`, for simulations with thousands of steps.
+    pub fn every(n: usize) -> Recorder {
+        assert!(n > 0, "can't keep one frame in 0");
+        Recorder {
+            frames: Vec::new(),
+            recording: true,
+            every: n,
+            offered: 0,
+        }
+    }
+
+    // A recorder that throws every frame away, for normal runs.
+    pub fn off() -> Recorder {
+        Recorder {
+            recording: false,
+            ..Recorder::new()
+        }
+    }
+
+    pub fn frame(&mut self, render: impl FnOnce() -> String) {
+        if !self.recording {
+            return;
+        }
+        if self.offered.is_multiple_of(self.every) {
+            self.frames.push(render());
+        }
+        self.offered += 1;
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    // Writes an asciicast v2 recording (https://docs.asciinema.org), one
+    // screen per frame, `delay` apart. The terminal is sized to fit the
+    // biggest frame.
+    pub fn write_cast(&self, mut out: impl Write, delay: Duration) -> io::Result<()> {
+        let width = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.lines())
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = self
+            .frames
+            .iter()
+            .map(|frame| frame.lines().count())
+            .max()
+            .unwrap_or(0);
+        let header = serde_json::json!({ "version": 2, "width": width, "height": height });
+        writeln!(out, "{header}")?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            let time = delay.as_secs_f64() * i as f64;
+            // home the cursor and clear the screen, then draw the frame
+            let text = format!("\x1b[H\x1b[2J{}", frame.replace('\n', "\r\n"));
+            writeln!(out, "{}", serde_json::json!([time, "o", text]))?;
+        }
+        Ok(())
+    }
+
+    // Writes each frame to its own numbered file in `dir`: frame_0001.txt,
+    // frame_0002.txt and so on. Frames left there by an earlier recording are
+    // deleted first, so a shorter recording doesn't end with stale ones.
+    pub fn write_frames(&self, dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("");
+            if name.starts_with("frame_") && name.ends_with(".txt") && path.is_file() {
+                fs::remove_file(&path)?;
+            }
+        }
+        let digits = self.frames.len().to_string().len().max(4);
+        for (i, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame_{:0digits$}.txt", i + 1));
+            fs::write(path, format!("{frame}\n"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Recorder {
+        Recorder::new()
+    }
+}
+
+// A day whose simulation can be watched step by step.
+pub trait Animated: Solution {
+    // Runs `part`, pushing a frame into `recorder` for each step.
+    fn animate(input: &Self::Input, part: u8, recorder: &mut Recorder);
+}
+
+pub fn record<S: Animated>(input: &str, part: u8, recorder: &mut Recorder) -> Result<(), AocError> {
+    let parsed = S::parse(input)?;
+    S::animate(&parsed, part, recorder);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded(recorder: &mut Recorder) {
+        for i in 1..=5 {
+            recorder.frame(|| format!("{i}\n{}", "#".repeat(i)));
+        }
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut recorder = Recorder::off();
+        recorded(&mut recorder);
+        assert!(recorder.frames().is_empty());
+
+        let mut recorder = Recorder::every(2);
+        recorded(&mut recorder);
+        assert_eq!(recorder.frames(), ["1\n#", "3\n###", "5\n#####"]);
+    }
+
+    #[test]
+    fn test_write_cast() {
+        let mut recorder = Recorder::every(2);
+        recorded(&mut recorder);
+        let mut out = Vec::new();
+        recorder
+            .write_cast(&mut out, Duration::from_millis(250))
+            .unwrap();
+        let cast = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = cast.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], r#"{"height":2,"version":2,"width":5}"#);
+        assert_eq!(lines[2], r#"[0.25,"o","\u001b[H\u001b[2J3\r\n###"]"#);
+    }
+
+    #[test]
+    fn test_write_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-frames-{}", std::process::id()));
+        let mut recorder = Recorder::new();
+        recorded(&mut recorder);
+        recorder.write_frames(&dir).unwrap();
+        let first = fs::read_to_string(dir.join("frame_0001.txt"));
+        let last = fs::read_to_string(dir.join("frame_0005.txt"));
+
+        // a shorter recording into the same directory replaces the old frames
+        let mut shorter = Recorder::every(3);
+        recorded(&mut shorter);
+        fs::write(dir.join("notes.txt"), "keep me").unwrap();
+        shorter.write_frames(&dir).unwrap();
+        let mut names: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        let second = fs::read_to_string(dir.join("frame_0002.txt"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first.unwrap(), "1\n#\n");
+        assert_eq!(last.unwrap(), "5\n#####\n");
+        assert_eq!(names, ["frame_0001.txt", "frame_0002.txt", "notes.txt"]);
+        assert_eq!(second.unwrap(), "4\n####\n");
+    }
+}
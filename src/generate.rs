@@ -0,0 +1,102 @@
+use crate::solution::Solution;
+
+// A small seeded random number generator (SplitMix64). Nowhere near good
+// enough for anything that matters, but the same seed always gives the same
+// input, so a failing case can be replayed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // A number from 0 up to but not including `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "nothing is below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // A number from `low` to `high`, both included.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "{low}..={high} is backwards");
+        let span = high.abs_diff(low) + 1;
+        low.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    // A number from 0 up to but not including 1.
+    pub fn fraction(&mut self) -> f64 {
+        // the top 53 bits fill an f64's mantissa exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // True about once in every `n` calls.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// A day that can make up valid puzzle inputs, for stress runs and for checking
+// the solution against a slower, obviously-right version of it.
+pub trait Generate: Solution {
+    // `size` is roughly how much input to make: elves, rounds, moves, monkeys
+    // and so on, whatever the day's input is a list of.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+pub fn generate<S: Generate>(seed: u64, size: usize) -> String {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+// An input for each seed from 0 up to `seeds`, along with the seed so a
+// failing test can say which one to replay.
+pub fn seeded_inputs<S: Generate>(seeds: u64, size: usize) -> impl Iterator<Item = (u64, String)> {
+    (0..seeds).map(move |seed| (seed, generate::<S>(seed, size)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let from_a: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let from_b: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        assert_eq!(from_a, from_b);
+        assert_ne!(Rng::new(8).next_u64(), from_a[0]);
+
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.between(-2, 2)));
+        }
+        assert_eq!(rng.between(5, 5), 5);
+        assert!((0..1000).all(|_| (0.0..1.0).contains(&rng.fraction())));
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
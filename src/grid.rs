@@ -0,0 +1,256 @@
+use std::fmt;
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+use crate::error::AocError;
+
+// A rectangular grid of cells stored row by row. Positions are
+// (row, column) pairs counted from the top left, matching how puzzle inputs
+// are laid out on the page.
+pub type Pos = (usize, usize);
+
+// Steps as (row, column) deltas, for neighbours and rays.
+pub const UP: (isize, isize) = (-1, 0);
+pub const DOWN: (isize, isize) = (1, 0);
+pub const LEFT: (isize, isize) = (0, -1);
+pub const RIGHT: (isize, isize) = (0, 1);
+pub const ORTHOGONAL: [(isize, isize); 4] = [UP, DOWN, LEFT, RIGHT];
+pub const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: Clone + Default> Grid<T> {
+    pub fn new(rows: usize, cols: usize) -> Grid<T> {
+        Grid {
+            cells: vec![T::default(); rows * cols],
+            rows,
+            cols,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // Builds a grid from lines of text, one cell per character. Rows must all
+    // be the same length; errors from `cell` get the line and column of the
+    // character that caused them.
+    pub fn from_str(
+        text: &str,
+        mut cell: impl FnMut(char) -> Result<T, AocError>,
+    ) -> Result<Grid<T>, AocError> {
+        let mut cells = Vec::new();
+        let mut cols = 0;
+        let mut rows = 0;
+        for (i, line) in text.trim_end_matches('\n').split('\n').enumerate() {
+            let start = cells.len();
+            for (j, c) in line.chars().enumerate() {
+                cells.push(cell(c).map_err(|err| err.at_line(i + 1).at_column(j + 1))?);
+            }
+            let width = cells.len() - start;
+            if i == 0 {
+                cols = width;
+            } else if width != cols {
+                return Err(AocError::new(format!("expected a row of {cols} cells")).at_line(i + 1));
+            }
+            rows += 1;
+        }
+        Ok(Grid { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.cells.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            self.cells.get_mut(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    // The first position whose cell matches, reading row by row.
+    pub fn find(&self, matches: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.positions().find(|&pos| matches(&self[pos]))
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[row * self.cols..(row + 1) * self.cols].iter()
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.cells[row * self.cols..(row + 1) * self.cols].iter_mut()
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn col_mut(&mut self, col: usize) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.cells.iter_mut().skip(col).step_by(self.cols)
+    }
+
+    // One step from `pos`, or None if that would leave the grid.
+    pub fn step(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+        if row < self.rows && col < self.cols {
+            Some((row, col))
+        } else {
+            None
+        }
+    }
+
+    // The up to four positions sharing an edge with `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |delta| self.step(pos, delta))
+    }
+
+    // The up to eight positions sharing an edge or a corner with `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .chain(DIAGONAL)
+            .filter_map(move |delta| self.step(pos, delta))
+    }
+
+    // The positions from `pos` (not including it) out to the edge of the
+    // grid, taking `delta` each step.
+    pub fn ray(&self, pos: Pos, delta: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        iter::successors(self.step(pos, delta), move |&pos| self.step(pos, delta))
+    }
+
+    // Draws the grid a row per line, turning each cell into a character.
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> Render<'_, T, F> {
+        Render { grid: self, cell }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", pos, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", pos, rows, cols),
+        }
+    }
+}
+
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
+}
+
+impl<T, F: Fn(&T) -> char> fmt::Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.grid.rows {
+            if row != 0 {
+                writeln!(f)?;
+            }
+            for cell in self.grid.row(row) {
+                write!(f, "{}", (self.cell)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Result<Grid<u32>, AocError> {
+        Grid::from_str(text, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::new(format!("expected a digit, found '{c}'")))
+        })
+    }
+
+    #[test]
+    fn test_from_str() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((0, 3)), None);
+
+        assert_eq!(
+            digits("123\n4x6").unwrap_err().to_string(),
+            "line 2, column 2: expected a digit, found 'x'"
+        );
+        assert_eq!(
+            digits("123\n45").unwrap_err().to_string(),
+            "line 2: expected a row of 3 cells"
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789").unwrap();
+        let mut corner: Vec<Pos> = grid.neighbours4((0, 0)).collect();
+        corner.sort();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_rays() {
+        let grid = digits("123\n456\n789").unwrap();
+        let values = |ray: Vec<Pos>| ray.into_iter().map(|pos| grid[pos]).collect::<Vec<u32>>();
+        assert_eq!(values(grid.ray((2, 1), UP).collect()), vec![5, 2]);
+        assert_eq!(values(grid.ray((1, 0), RIGHT).collect()), vec![5, 6]);
+        assert_eq!(values(grid.ray((0, 0), (1, 1)).collect()), vec![5, 9]);
+        assert_eq!(grid.ray((0, 0), LEFT).count(), 0);
+        assert_eq!(
+            grid.col(1).rev().copied().collect::<Vec<u32>>(),
+            vec![8, 5, 2]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = digits("10\n01").unwrap();
+        let picture = grid.render(|&d| if d == 1 { '#' } else { '.' });
+        assert_eq!(picture.to_string(), "#.\n.#");
+    }
+}
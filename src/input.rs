@@ -0,0 +1,106 @@
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+// Where a day's puzzle input comes from, in order of preference:
+// an explicit path ("-" meaning stdin), then $AOC_INPUT_DIR/NN/input.txt,
+// then the default src/NN/input.txt.
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    pub fn resolve(day: u8, input: Option<&Path>) -> Source {
+        resolve_with(day, input, env::var_os("AOC_INPUT_DIR"))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        let raw = match self {
+            Source::Stdin => {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw)?;
+                raw
+            }
+            Source::File(path) => fs::read_to_string(path)?,
+        };
+        Ok(normalize(&raw))
+    }
+
+    // The input as a reader, for days that can go through it a line at a
+    // time. Nothing is normalised, so the day has to cope with CRLF itself.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::File(path) => Box::new(BufReader::new(fs::File::open(path)?)),
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+fn resolve_with(day: u8, input: Option<&Path>, input_dir: Option<OsString>) -> Source {
+    match input {
+        Some(path) if path == Path::new("-") => Source::Stdin,
+        Some(path) => Source::File(path.to_path_buf()),
+        None => {
+            let dir = match input_dir {
+                Some(dir) => PathBuf::from(dir),
+                None => PathBuf::from("src"),
+            };
+            Source::File(dir.join(format!("{:02}", day)).join("input.txt"))
+        }
+    }
+}
+
+// Turns CRLF line endings into LF and leaves exactly one trailing newline, so
+// parsers see the same text however the file was saved. Blank lines inside
+// the input (like the ones separating day 1's elves) are kept.
+pub fn normalize(raw: &str) -> String {
+    let mut normalized = raw.replace("\r\n", "\n");
+    let trimmed_len = normalized.trim_end_matches('\n').len();
+    normalized.truncate(trimmed_len);
+    normalized.push('\n');
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3\n");
+        assert_eq!(normalize("1\n2\n\n\n"), "1\n2\n");
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve_with(9, Some(Path::new("-")), None), Source::Stdin);
+        assert_eq!(
+            resolve_with(9, Some(Path::new("mine.txt")), Some("inputs".into())),
+            Source::File(PathBuf::from("mine.txt"))
+        );
+        assert_eq!(
+            resolve_with(9, None, Some("inputs".into())),
+            Source::File(PathBuf::from("inputs/09/input.txt"))
+        );
+        assert_eq!(
+            resolve_with(9, None, None),
+            Source::File(PathBuf::from("src/09/input.txt"))
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::iter::FromIterator;
+
+// A run of whole numbers from `start` to `end`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        assert!(start <= end, "interval {start}..={end} is backwards");
+        Interval { start, end }
+    }
+
+    // How many numbers the interval holds.
+    pub fn size(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn is_subset(&self, other: &Interval) -> bool {
+        other.start <= self.start && self.end <= other.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        if self.overlaps(other) {
+            Some(Interval::new(
+                self.start.max(other.start),
+                self.end.min(other.end),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+// A set of whole numbers kept as sorted intervals. Overlapping or touching
+// intervals are merged as they're inserted, so no two stored intervals
+// overlap and there's always a gap between neighbours.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // everything from `first` up to `last` touches the new interval
+        let first = self
+            .intervals
+            .partition_point(|i| i.end < interval.start.saturating_sub(1));
+        let last = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // How many numbers are in the set.
+    pub fn covered(&self) -> i64 {
+        self.intervals.iter().map(|i| i.size()).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.covers(&Interval::new(value, value))
+    }
+
+    // Whether every number in `interval` is in the set.
+    pub fn covers(&self, interval: &Interval) -> bool {
+        let index = self.intervals.partition_point(|i| i.end < interval.start);
+        self.intervals
+            .get(index)
+            .is_some_and(|i| interval.is_subset(i))
+    }
+
+    // Whether any number in `interval` is in the set.
+    pub fn overlaps(&self, interval: &Interval) -> bool {
+        let index = self.intervals.partition_point(|i| i.end < interval.start);
+        self.intervals
+            .get(index)
+            .is_some_and(|i| i.overlaps(interval))
+    }
+
+    // The part of the set that falls within `bounds`.
+    pub fn clip(&self, bounds: &Interval) -> IntervalSet {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .iter()
+                .filter_map(|i| i.intersection(bounds))
+                .collect(),
+        }
+    }
+
+    // The runs of numbers within `bounds` that aren't in the set.
+    pub fn gaps(&self, bounds: &Interval) -> Vec<Interval> {
+        let mut gaps = Vec::new();
+        let mut next = bounds.start;
+        for interval in self.clip(bounds).iter() {
+            if interval.start > next {
+                gaps.push(Interval::new(next, interval.start - 1));
+            }
+            if interval.end == bounds.end {
+                return gaps;
+            }
+            next = interval.end + 1;
+        }
+        if next <= bounds.end {
+            gaps.push(Interval::new(next, bounds.end));
+        }
+        gaps
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2, 4);
+        let b = Interval::new(4, 8);
+        assert_eq!(a.size(), 3);
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&Interval::new(5, 5)));
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 4)));
+        assert!(Interval::new(5, 6).is_subset(&b));
+        assert!(!a.is_subset(&b));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set: IntervalSet = [Interval::new(1, 3), Interval::new(10, 12)]
+            .into_iter()
+            .collect();
+        set.insert(Interval::new(6, 7));
+        assert_eq!(set.iter().count(), 3);
+        // touching intervals merge, as there are no numbers between them
+        set.insert(Interval::new(4, 5));
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(1, 7), Interval::new(10, 12)]
+        );
+        set.insert(Interval::new(-2, 20));
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(-2, 20)]
+        );
+        assert_eq!(set.covered(), 23);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set: IntervalSet = [Interval::new(0, 4), Interval::new(8, 9)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.covered(), 7);
+        assert!(set.contains(9));
+        assert!(!set.contains(5));
+        assert!(set.covers(&Interval::new(1, 3)));
+        assert!(!set.covers(&Interval::new(3, 8)));
+        assert!(set.overlaps(&Interval::new(5, 8)));
+        assert!(!set.overlaps(&Interval::new(5, 7)));
+        assert_eq!(
+            set.clip(&Interval::new(2, 8))
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![Interval::new(2, 4), Interval::new(8, 8)]
+        );
+        assert_eq!(
+            set.gaps(&Interval::new(-1, 12)),
+            vec![
+                Interval::new(-1, -1),
+                Interval::new(5, 7),
+                Interval::new(10, 12)
+            ]
+        );
+        assert_eq!(set.gaps(&Interval::new(1, 3)), vec![]);
+    }
+}
@@ -0,0 +1,66 @@
+pub mod answers;
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day18;
+pub mod error;
+pub mod frames;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod interval_set;
+pub mod parse;
+pub mod point;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+pub mod tree;
+
+use solution::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1)
+        .generated::<day01::Day01>()
+        .streaming::<day01::Day01>(),
+    Day::new::<day02::Day02>(2)
+        .generated::<day02::Day02>()
+        .with_rules::<day02::Day02>(),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5).generated::<day05::Day05>(),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9)
+        .animated::<day09::Day09>()
+        .generated::<day09::Day09>(),
+    Day::new::<day10::Day10>(10)
+        .animated::<day10::Day10>()
+        .generated::<day10::Day10>(),
+    Day::new::<day11::Day11>(11)
+        .animated::<day11::Day11>()
+        .generated::<day11::Day11>(),
+    Day::new::<day12::Day12>(12),
+    Day::new::<day13::Day13>(13).generated::<day13::Day13>(),
+    Day::new::<day14::Day14>(14)
+        .animated::<day14::Day14>()
+        .generated::<day14::Day14>(),
+    Day::new::<day15::Day15>(15).generated::<day15::Day15>(),
+    Day::new::<day16::Day16>(16).generated::<day16::Day16>(),
+    Day::new::<day18::Day18>(18).generated::<day18::Day18>(),
+];
@@ -1,24 +1,509 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
 
-fn part1(contents: &str) {
-    println!("Part 1");
+use aoc2022::answers::{self, Answers, Verdict};
+use aoc2022::bench::Stats;
+use aoc2022::frames::Recorder;
+use aoc2022::input::Source;
+use aoc2022::scaffold;
+use aoc2022::solution::Day;
+use aoc2022::DAYS;
 
-    let answer = "";
-    println!("Answer: {}", answer);
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    /// Log what the solutions are doing: -v for debug, -vv for trace
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
 }
 
-fn main() {
-    let contents =
-        fs::read_to_string("src/18/input.txt").expect("Should have been able to read the file");
-    part1(&contents);
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solution
+    Run {
+        /// Day of the puzzle, 1 to 25
+        #[arg(short, long)]
+        day: u8,
+        /// Part to run; runs both parts if left out
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, or - for stdin; defaults to
+        /// $AOC_INPUT_DIR/NN/input.txt, then src/NN/input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// How to print the answers
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Read the input a line at a time instead of all at once, for
+        /// inputs too big for memory (only some days can)
+        #[arg(long)]
+        stream: bool,
+        /// Rule set to read the input under, for days that have more than
+        /// one (day 2: classic or rpsls)
+        #[arg(long, conflicts_with = "stream")]
+        rules: Option<String>,
+    },
+    /// Check every day's answers against the answers file
+    Verify {
+        /// Answers file, keyed by day and part
+        #[arg(short, long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Time parse, part 1 and part 2 for every day
+    Bench {
+        /// Only bench this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// How many times to run each day
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Also write the results to this CSV file
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+    /// Record a day's simulation, one frame per step
+    Record {
+        /// Day of the puzzle: 9, 10, 11 or 14
+        #[arg(short, long)]
+        day: u8,
+        /// Part to record
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input file, or - for stdin; same default as run
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// An asciinema .cast file, or a directory for numbered text frames
+        #[arg(short, long)]
+        out: PathBuf,
+        /// Only keep one frame in every N
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        every: u32,
+        /// Milliseconds between frames when playing back a .cast
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+    },
+    /// Make up a random puzzle input and print it
+    Generate {
+        /// Day of the puzzle, 1 to 25
+        #[arg(short, long)]
+        day: u8,
+        /// The same seed always makes the same input
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how big: how many elves, rounds, moves, monkeys, ...
+        #[arg(short = 'n', long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Start a new day from the template and register it
+    New {
+        /// Day of the puzzle, 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One "Day N part P: answer" line per part
+    Text,
+    /// One JSON record per part, with how long it took
+    Json,
+}
+
+fn print_answer(day: u8, part: u8, answer: &str) {
+    if answer.contains('\n') {
+        // multi-line answers (like day 10's screen) read better on their own lines
+        println!("Day {} part {}:\n{}", day, part, answer);
+    } else {
+        println!("Day {} part {}: {}", day, part, answer);
+    }
+}
+
+// Each part's answer and how long it took, reading the whole input first.
+fn solve_answers(
+    solution: &Day,
+    source: &Source,
+    parts: &[u8],
+    rules: Option<&str>,
+) -> Vec<(String, Duration)> {
+    let with_rules = rules.map(|rules| {
+        let solve = solution.solve_with_rules.unwrap_or_else(|| {
+            eprintln!("Day {} has no rule sets to pick from", solution.number);
+            process::exit(1);
+        });
+        (solve, rules)
+    });
+    let contents = source.read().unwrap_or_else(|err| {
+        eprintln!("Could not read {}: {}", source, err);
+        process::exit(1);
+    });
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match with_rules {
+                Some((solve, rules)) => solve(&contents, part, rules),
+                None => (solution.solve)(&contents, part),
+            };
+            let answer = answer.unwrap_or_else(|err| {
+                eprintln!("Could not parse {}: {}", source, err);
+                process::exit(1);
+            });
+            (answer, start.elapsed())
+        })
+        .collect()
+}
+
+// Same as solve_answers, but in one pass over the input as it's read. Both
+// parts come out of the same pass, so they share its time.
+fn stream_answers(solution: &Day, source: &Source, parts: &[u8]) -> Vec<(String, Duration)> {
+    let stream = solution.stream.unwrap_or_else(|| {
+        eprintln!("Day {} can't stream its input", solution.number);
+        process::exit(1);
+    });
+    let mut reader = source.open().unwrap_or_else(|err| {
+        eprintln!("Could not read {}: {}", source, err);
+        process::exit(1);
+    });
+    let start = Instant::now();
+    let answers = stream(&mut reader).unwrap_or_else(|err| {
+        eprintln!("Could not parse {}: {}", source, err);
+        process::exit(1);
+    });
+    let elapsed = start.elapsed();
+    parts
+        .iter()
+        .map(|&part| (answers[part as usize - 1].clone(), elapsed))
+        .collect()
+}
+
+// Keeps multi-line answers (day 10's screen) from breaking up the table.
+fn one_line(answer: &str) -> String {
+    match answer.trim_end().split_once('\n') {
+        Some((first, _)) => format!("{first} ..."),
+        None => answer.to_string(),
+    }
+}
+
+fn verify(answers: &Answers) -> bool {
+    let mut verdicts = Vec::new();
+    println!("{:>3}  {:>4}  {:<7}  Answer", "Day", "Part", "Result");
+    for day in DAYS {
+        let source = Source::resolve(day.number, None);
+        let contents = source.read();
+        for part in [1, 2] {
+            let expected = answers.get(day.number, part);
+            let (verdict, detail) = match &contents {
+                Err(_) => (Verdict::Missing, format!("no input at {source}")),
+                Ok(contents) => match (day.solve)(contents, part) {
+                    Err(err) => (Verdict::Fail, format!("could not parse: {err}")),
+                    Ok(answer) => match answers::check(expected, &answer) {
+                        Verdict::Fail => (
+                            Verdict::Fail,
+                            format!(
+                                "{} (expected {})",
+                                one_line(&answer),
+                                one_line(expected.unwrap())
+                            ),
+                        ),
+                        verdict => (verdict, one_line(&answer)),
+                    },
+                },
+            };
+            println!("{:>3}  {:>4}  {:<7}  {}", day.number, part, verdict, detail);
+            verdicts.push(verdict);
+        }
+    }
+    let count = |verdict: Verdict| verdicts.iter().filter(|v| **v == verdict).count();
+    println!(
+        "{} passed, {} failed, {} missing",
+        count(Verdict::Pass),
+        count(Verdict::Fail),
+        count(Verdict::Missing)
+    );
+    count(Verdict::Fail) == 0
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn bench(day: Option<u8>, iterations: usize, csv: Option<PathBuf>) {
+    let mut rows: Vec<(u8, &str, Stats)> = Vec::new();
+    for solution in DAYS
+        .iter()
+        .filter(|d| day.is_none() || day == Some(d.number))
+    {
+        let source = Source::resolve(solution.number, None);
+        let contents = match source.read() {
+            Ok(contents) => contents,
+            Err(err) => {
+                eprintln!(
+                    "Skipping day {}: could not read {}: {}",
+                    solution.number, source, err
+                );
+                continue;
+            }
+        };
+        match (solution.bench)(&contents, iterations) {
+            Ok(timings) => {
+                rows.push((solution.number, "parse", timings.parse));
+                rows.push((solution.number, "part1", timings.part1));
+                rows.push((solution.number, "part2", timings.part2));
+            }
+            Err(err) => eprintln!(
+                "Skipping day {}: could not parse {}: {}",
+                solution.number, source, err
+            ),
+        }
+    }
+
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+        "Day", "Phase", "Mean (ms)", "Min (ms)", "Max (ms)"
+    );
+    for (day, phase, stats) in &rows {
+        println!(
+            "{:>3}  {:<5}  {:>12.3}  {:>12.3}  {:>12.3}",
+            day,
+            phase,
+            millis(stats.mean),
+            millis(stats.min),
+            millis(stats.max)
+        );
+    }
+
+    if let Some(path) = csv {
+        let mut out = String::from("day,phase,iterations,mean_ms,min_ms,max_ms\n");
+        for (day, phase, stats) in &rows {
+            out.push_str(&format!(
+                "{},{},{},{:.6},{:.6},{:.6}\n",
+                day,
+                phase,
+                iterations,
+                millis(stats.mean),
+                millis(stats.min),
+                millis(stats.max)
+            ));
+        }
+        fs::write(&path, out).unwrap_or_else(|err| {
+            eprintln!("Could not write {}: {}", path.display(), err);
+            process::exit(1);
+        });
+    }
+}
+
+fn record(day: u8, part: u8, input: Option<&Path>, out: &Path, every: usize, delay: Duration) {
+    let record = DAYS
+        .iter()
+        .find(|d| d.number == day)
+        .and_then(|d| d.record)
+        .unwrap_or_else(|| {
+            eprintln!("Day {} has no simulation to record", day);
+            process::exit(1);
+        });
+    let source = Source::resolve(day, input);
+    let contents = source.read().unwrap_or_else(|err| {
+        eprintln!("Could not read {}: {}", source, err);
+        process::exit(1);
+    });
+
+    let mut recorder = Recorder::every(every);
+    record(&contents, part, &mut recorder).unwrap_or_else(|err| {
+        eprintln!("Could not parse {}: {}", source, err);
+        process::exit(1);
+    });
+    if recorder.frames().is_empty() {
+        eprintln!("Day {} part {} has nothing to record", day, part);
+        process::exit(1);
+    }
+
+    let result = if out.extension().is_some_and(|ext| ext == "cast") {
+        fs::File::create(out).and_then(|file| recorder.write_cast(io::BufWriter::new(file), delay))
+    } else {
+        recorder.write_frames(out)
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("Could not write {}: {}", out.display(), err);
+        process::exit(1);
+    });
+    println!(
+        "Recorded {} frames to {}",
+        recorder.frames().len(),
+        out.display()
+    );
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+// Writes a file that shouldn't exist yet, making its directory if needed.
+fn create(path: &Path, contents: &str) {
+    if path.exists() {
+        eprintln!("{} already exists, leaving it alone", path.display());
+        return;
+    }
+    let result = match path.parent() {
+        Some(dir) => fs::create_dir_all(dir).and_then(|_| fs::write(path, contents)),
+        None => fs::write(path, contents),
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("Could not write {}: {}", path.display(), err);
+        process::exit(1);
+    });
+    println!("Created {}", path.display());
+}
+
+fn new_day(day: u8) {
+    let lib_path = Path::new("src/lib.rs");
+    let lib_rs = fs::read_to_string(lib_path).unwrap_or_else(|err| {
+        eprintln!(
+            "Could not read {} (run this from the repo root): {}",
+            lib_path.display(),
+            err
+        );
+        process::exit(1);
+    });
+    let lib_rs = scaffold::register(&lib_rs, day).unwrap_or_else(|| {
+        eprintln!(
+            "Day {} is already registered in {}",
+            day,
+            lib_path.display()
+        );
+        process::exit(1);
+    });
+
+    create(
+        &PathBuf::from(format!("src/day{:02}/mod.rs", day)),
+        &scaffold::day_module(day),
+    );
+    if let Source::File(input) = Source::resolve(day, None) {
+        create(&input, "");
+    }
+    create(
+        &PathBuf::from(format!("examples/{:02}/example.txt", day)),
+        "",
+    );
+    fs::write(lib_path, lib_rs).unwrap_or_else(|err| {
+        eprintln!("Could not write {}: {}", lib_path.display(), err);
+        process::exit(1);
+    });
+    println!("Registered day {} in {}", day, lib_path.display());
+}
+
+// Logs go to stderr so they never mix with the answers. RUST_LOG still works
+// for finer control, e.g. RUST_LOG=aoc2022::day11=trace.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format_timestamp(None)
+        .init();
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            stream,
+            rules,
+        } => {
+            let solution = DAYS.iter().find(|d| d.number == day).unwrap_or_else(|| {
+                eprintln!("Day {} has no Rust solution yet", day);
+                process::exit(1);
+            });
 
-    #[test]
-    fn test_part_1() {
-        assert_eq!(2 + 2, 4)
+            let source = Source::resolve(day, input.as_deref());
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let answers = if stream {
+                stream_answers(solution, &source, &parts)
+            } else {
+                solve_answers(solution, &source, &parts, rules.as_deref())
+            };
+            for (part, (answer, elapsed)) in parts.into_iter().zip(answers) {
+                match format {
+                    Format::Text => print_answer(day, part, &answer),
+                    Format::Json => {
+                        let record = serde_json::json!({
+                            "day": day,
+                            "part": part,
+                            "answer": answer,
+                            "elapsed_ms": millis(elapsed),
+                        });
+                        println!("{}", record);
+                    }
+                }
+            }
+        }
+        Command::Verify { answers } => {
+            let text = fs::read_to_string(&answers).unwrap_or_else(|err| {
+                eprintln!("Could not read {}: {}", answers.display(), err);
+                process::exit(1);
+            });
+            let answers = Answers::parse(&text).unwrap_or_else(|err| {
+                eprintln!("Could not parse {}: {}", answers.display(), err);
+                process::exit(1);
+            });
+            if !verify(&answers) {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            csv,
+        } => {
+            if let Some(day) = day {
+                if !DAYS.iter().any(|d| d.number == day) {
+                    eprintln!("Day {} has no Rust solution yet", day);
+                    process::exit(1);
+                }
+            }
+            bench(day, iterations as usize, csv);
+        }
+        Command::Record {
+            day,
+            part,
+            input,
+            out,
+            every,
+            delay,
+        } => record(
+            day,
+            part,
+            input.as_deref(),
+            &out,
+            every as usize,
+            Duration::from_millis(delay),
+        ),
+        Command::Generate { day, seed, size } => {
+            let generate = DAYS
+                .iter()
+                .find(|d| d.number == day)
+                .and_then(|d| d.generate)
+                .unwrap_or_else(|| {
+                    eprintln!("Day {} has no input generator", day);
+                    process::exit(1);
+                });
+            print!("{}", generate(seed, size));
+        }
+        Command::New { day } => new_day(day),
     }
 }
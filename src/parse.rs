@@ -0,0 +1,207 @@
+use std::str::FromStr;
+
+use crate::error::{parse_number, AocError};
+
+// A run of non-blank lines from the input, as found between blank lines.
+#[derive(Debug, PartialEq)]
+pub struct Record<'a> {
+    // 1-based line number of the record's first line in the whole input
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Record<'a> {
+    // The record's lines, each with its line number in the whole input.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        let first_line = self.first_line;
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(i, &line)| (first_line + i, line))
+    }
+
+    pub fn last_line(&self) -> usize {
+        self.first_line + self.lines.len() - 1
+    }
+}
+
+// Splits the input into groups of lines separated by blank lines (like day
+// 1's elves or day 11's monkeys). Any number of blank lines counts as one
+// separator, and the last record doesn't need a blank line after it.
+pub fn records(input: &str) -> impl Iterator<Item = Record<'_>> {
+    let mut lines = input.lines().enumerate().peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {}
+        let (first, line) = lines.next()?;
+        let mut record = Record {
+            first_line: first + 1,
+            lines: vec![line],
+        };
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            record.lines.push(line);
+        }
+        Some(record)
+    })
+}
+
+// Every integer in `text`, in order, ignoring whatever's around them. A '-'
+// right before the digits makes the number negative, unless it comes straight
+// after another number (so "2-4" is 2 and 4, not 2 and -4).
+pub fn ints<T: FromStr>(text: &str) -> Result<Vec<T>, AocError> {
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        numbers.push(parse_number(&text[start..i])?);
+    }
+    Ok(numbers)
+}
+
+// Matches `line` against `template`, where each "{}" stands for a field and
+// everything else has to appear exactly. Returns the text of each field, or
+// None if the line doesn't fit. A field runs up to the first place the
+// template's next piece of literal text appears.
+pub fn scan_fields<'a>(line: &'a str, template: &str) -> Option<Vec<&'a str>> {
+    let mut pieces = template.split("{}");
+    let mut rest = line.strip_prefix(pieces.next()?)?;
+    let mut pieces = pieces.peekable();
+    let mut fields = Vec::new();
+    while let Some(piece) = pieces.next() {
+        if pieces.peek().is_none() {
+            // the last field takes everything up to the template's ending
+            fields.push(rest.strip_suffix(piece)?);
+            return Some(fields);
+        }
+        let end = if piece.is_empty() {
+            0
+        } else {
+            rest.find(piece)?
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + piece.len()..];
+    }
+    // a template with no fields
+    rest.is_empty().then_some(fields)
+}
+
+// Turns the fields `scan!` found into a typed tuple.
+pub trait FromFields: Sized {
+    fn from_fields(fields: &[&str]) -> Result<Self, AocError>;
+}
+
+macro_rules! tuple_from_fields {
+    ($($t:ident),*) => {
+        impl<$($t: FromStr),*> FromFields for ($($t,)*) {
+            fn from_fields(fields: &[&str]) -> Result<Self, AocError> {
+                let mut fields = fields.iter();
+                let tuple = ($(parse_number::<$t>(fields.next().expect("the template has too few fields"))?,)*);
+                assert!(fields.next().is_none(), "the template has too many fields");
+                Ok(tuple)
+            }
+        }
+    };
+}
+
+tuple_from_fields!(A);
+tuple_from_fields!(A, B);
+tuple_from_fields!(A, B, C);
+tuple_from_fields!(A, B, C, D);
+tuple_from_fields!(A, B, C, D, E);
+tuple_from_fields!(A, B, C, D, E, F);
+
+// Matches a line against a template with "{}" for each field, parsing the
+// fields into a tuple whose types come from how the result is used:
+//
+//     let (count, from, to): (u32, usize, usize) =
+//         scan!(line, "move {} from {} to {}", "move N from A to B")?;
+//
+// The last argument describes the expected line for the error when it doesn't
+// match the template, and defaults to the template itself.
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $template:literal) => {
+        $crate::scan!($line, $template, $template)
+    };
+    ($line:expr, $template:literal, $expected:expr) => {
+        match $crate::parse::scan_fields($line, $template) {
+            Some(fields) => $crate::parse::FromFields::from_fields(&fields),
+            None => Err($crate::error::AocError::new(format!(
+                "expected '{}'",
+                $expected
+            ))),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        let input = "1\n2\n\n3\n\n\n4\n5";
+        let found: Vec<Record> = records(input).collect();
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].lines, vec!["1", "2"]);
+        assert_eq!(found[2].first_line, 7);
+        assert_eq!(found[2].last_line(), 8);
+        assert_eq!(found[1].numbered().collect::<Vec<_>>(), vec![(4, "3")]);
+        assert_eq!(records("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            ints::<i32>("Sensor at x=-2, y=15: beacon at x=3, y=-10").unwrap(),
+            vec![-2, 15, 3, -10]
+        );
+        assert_eq!(ints::<u32>("2-4,6-8").unwrap(), vec![2, 4, 6, 8]);
+        assert_eq!(ints::<i32>("no numbers - here").unwrap(), Vec::<i32>::new());
+        assert_eq!(
+            ints::<u8>("1, 300").unwrap_err().message,
+            "expected a number, found '300'"
+        );
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan_fields("move 1 from 2 to 3", "move {} from {} to {}"),
+            Some(vec!["1", "2", "3"])
+        );
+        assert_eq!(scan_fields("Monkey 4:", "Monkey {}:"), Some(vec!["4"]));
+        assert_eq!(
+            scan_fields("shift 1 from 2 to 3", "move {} from {} to {}"),
+            None
+        );
+        assert_eq!(scan_fields("Monkey 4", "Monkey {}:"), None);
+
+        let parsed: Result<(i64, i64), AocError> = scan!("x=-2, y=15", "x={}, y={}");
+        assert_eq!(parsed, Ok((-2, 15)));
+        let parsed: Result<(String, u32), AocError> = scan!("AA=three", "{}={}");
+        assert_eq!(
+            parsed.unwrap_err().message,
+            "expected a number, found 'three'"
+        );
+        let parsed: Result<(u32,), AocError> =
+            scan!("Test: 7", "Test: divisible by {}", "Test: divisible by N");
+        assert_eq!(
+            parsed.unwrap_err().message,
+            "expected 'Test: divisible by N'"
+        );
+    }
+}
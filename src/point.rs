@@ -0,0 +1,279 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+// The integer types a point's coordinates can be. Unsigned coordinates can't
+// go below zero, so stepping off the low edge gives None instead of wrapping.
+pub trait Coord: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {
+    fn abs_diff(self, other: Self) -> Self;
+    fn signum(self) -> Self;
+    fn offset(self, delta: i8) -> Option<Self>;
+    fn to_usize(self) -> Option<usize>;
+    fn from_usize(value: usize) -> Option<Self>;
+}
+
+macro_rules! signed_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                fn abs_diff(self, other: Self) -> Self {
+                    (self - other).abs()
+                }
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+                fn offset(self, delta: i8) -> Option<Self> {
+                    self.checked_add(delta as $t)
+                }
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+                fn from_usize(value: usize) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! unsigned_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                fn abs_diff(self, other: Self) -> Self {
+                    <$t>::abs_diff(self, other)
+                }
+                fn signum(self) -> Self {
+                    (self > 0) as $t
+                }
+                fn offset(self, delta: i8) -> Option<Self> {
+                    self.checked_add_signed(delta as _)
+                }
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+                fn from_usize(value: usize) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+signed_coord!(i32, i64, isize);
+unsigned_coord!(u32, u64, usize);
+
+const ORTHOGONAL_2: [(i8, i8); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+const DIAGONAL_2: [(i8, i8); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+// x grows to the right and y grows down the page, like the puzzle drawings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Point2<T>) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn signum(self) -> Point2<T> {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    // The point moved by (dx, dy), if that still fits in T.
+    pub fn offset(self, dx: i8, dy: i8) -> Option<Point2<T>> {
+        Some(Point2::new(self.x.offset(dx)?, self.y.offset(dy)?))
+    }
+
+    // The (row, column) index of this point in a `Grid`, or None if either
+    // coordinate is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((self.y.to_usize()?, self.x.to_usize()?))
+    }
+
+    pub fn from_index((row, col): (usize, usize)) -> Option<Point2<T>> {
+        Some(Point2::new(T::from_usize(col)?, T::from_usize(row)?))
+    }
+
+    // The up to four points sharing an edge with this one.
+    pub fn orthogonal(self) -> impl Iterator<Item = Point2<T>> {
+        ORTHOGONAL_2
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+
+    // The up to four points touching this one at a corner.
+    pub fn diagonal(self) -> impl Iterator<Item = Point2<T>> {
+        DIAGONAL_2
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+}
+
+impl<T: Coord> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        *self = *self - other;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(self, other: Point3<T>) -> T {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    pub fn signum(self) -> Point3<T> {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn offset(self, dx: i8, dy: i8, dz: i8) -> Option<Point3<T>> {
+        Some(Point3::new(
+            self.x.offset(dx)?,
+            self.y.offset(dy)?,
+            self.z.offset(dz)?,
+        ))
+    }
+
+    // The up to six points sharing a face with this one.
+    pub fn orthogonal(self) -> impl Iterator<Item = Point3<T>> {
+        neighbour_offsets_3()
+            .filter(|&(dx, dy, dz)| dx.abs() + dy.abs() + dz.abs() == 1)
+            .filter_map(move |(dx, dy, dz)| self.offset(dx, dy, dz))
+    }
+
+    // The up to twenty points touching this one only at an edge or a corner.
+    pub fn diagonal(self) -> impl Iterator<Item = Point3<T>> {
+        neighbour_offsets_3()
+            .filter(|&(dx, dy, dz)| dx.abs() + dy.abs() + dz.abs() > 1)
+            .filter_map(move |(dx, dy, dz)| self.offset(dx, dy, dz))
+    }
+}
+
+fn neighbour_offsets_3() -> impl Iterator<Item = (i8, i8, i8)> {
+    (-1..=1)
+        .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+        .filter(|&offset| offset != (0, 0, 0))
+}
+
+impl<T: Coord> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Coord> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Point3<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Point3<T>) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((a - b).signum(), Point2::new(-1, -1));
+        assert_eq!(a.to_index(), None);
+        assert_eq!(b.to_index(), Some((2, 4)));
+        assert_eq!(Point2::<i32>::from_index((2, 4)), Some(b));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(Point2::new(0, 0).orthogonal().count(), 4);
+        assert_eq!(Point2::new(0, 0).diagonal().count(), 4);
+        // unsigned points stop at zero rather than wrapping round
+        let mut corner: Vec<Point2<usize>> = Point2::new(0, 0).orthogonal().collect();
+        corner.sort();
+        assert_eq!(corner, vec![Point2::new(0, 1), Point2::new(1, 0)]);
+        assert_eq!(Point2::<usize>::new(0, 3).diagonal().count(), 2);
+
+        assert_eq!(Point3::new(0, 0, 0).orthogonal().count(), 6);
+        assert_eq!(Point3::new(0, 0, 0).diagonal().count(), 20);
+    }
+
+    #[test]
+    fn test_point3() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(2, 0, 7);
+        assert_eq!(b - a, Point3::new(1, -2, 4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((a - b).signum(), Point3::new(-1, 1, -1));
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Point3::new(3, 2, 10));
+        c -= a;
+        assert_eq!(c, b);
+    }
+}
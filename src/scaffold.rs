@@ -0,0 +1,114 @@
+// Templates for `aoc2022 new`, which starts a day with the same skeleton
+// every time: a Solution with both parts unsolved, registered in DAYS, with
+// an example fixture waiting for its answers.
+
+pub fn day_module(day: u8) -> String {
+    TEMPLATE.replace("{NN}", &format!("{:02}", day))
+}
+
+const TEMPLATE: &str = "use crate::error::AocError;
+use crate::solution::{Solution, Unsolved};
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    type Input = Vec<String>;
+    type Output1 = Unsolved;
+    type Output2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(_lines: &Vec<String>) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day{NN},
+        example: \"{NN}/example.txt\" {
+            // part1 = ...,
+        },
+    }
+}
+";
+
+// Adds the day's `pub mod` line and its DAYS entry to lib.rs, keeping both in
+// day order. Returns None if the day is already registered.
+pub fn register(lib_rs: &str, day: u8) -> Option<String> {
+    let module = format!("day{:02}", day);
+    let mod_line = format!("pub mod {};", module);
+    let days_line = format!("    Day::new::<{}::Day{:02}>({}),", module, day, day);
+    if lib_rs.lines().any(|line| line == mod_line) {
+        return None;
+    }
+
+    let mut lines: Vec<String> = lib_rs.lines().map(|line| line.to_string()).collect();
+    insert_sorted(&mut lines, mod_line, |line| line.starts_with("pub mod day"));
+    insert_sorted(&mut lines, days_line, |line| {
+        line.starts_with("    Day::new::<day")
+    });
+    Some(lines.join("\n") + "\n")
+}
+
+// Puts `new_line` among the run of lines matching `is_entry`, just before the
+// first one that sorts after it. Zero-padded day numbers sort as text.
+fn insert_sorted(lines: &mut Vec<String>, new_line: String, is_entry: impl Fn(&str) -> bool) {
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(&lines[i])).collect();
+    let index = entries
+        .iter()
+        .find(|&&i| lines[i] > new_line)
+        .copied()
+        .unwrap_or_else(|| entries.last().map_or(lines.len(), |last| last + 1));
+    lines.insert(index, new_line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let lib_rs = "pub mod day01;
+pub mod day18;
+pub mod error;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day18::Day18>(18),
+];
+";
+        assert_eq!(
+            register(lib_rs, 7).unwrap(),
+            "pub mod day01;
+pub mod day07;
+pub mod day18;
+pub mod error;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day18::Day18>(18),
+];
+"
+        );
+        assert!(register(&register(lib_rs, 19).unwrap(), 19).is_none());
+        assert!(register(lib_rs, 18).is_none());
+    }
+
+    #[test]
+    fn test_day_module() {
+        let module = day_module(19);
+        assert!(module.contains("impl Solution for Day19 {"));
+        assert!(module.contains("example: \"19/example.txt\""));
+    }
+}
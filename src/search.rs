@@ -0,0 +1,276 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+// Graph searches over a `neighbours` closure, so a day can search a graph
+// without building it first. Every search takes any number of start nodes
+// and stops as soon as it reaches a node matching `is_goal`; pass `|_| false`
+// to explore everything reachable instead.
+
+pub struct Search<N> {
+    costs: HashMap<N, usize>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Hash + Eq + Clone> Search<N> {
+    fn new() -> Search<N> {
+        Search {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    // The goal node the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    // The cheapest cost from any start to `node`, if the search got there.
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    // Every node the search settled, with its cost.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, usize)> {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    // The cheapest path to `node`, from the start it was reached from up to
+    // and including `node` itself.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+// Breadth-first search, where every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let cost = search.costs[&node] + 1;
+        for next in neighbours(&node) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+// Cheapest-first search, where `neighbours` gives each next node along with
+// the cost of stepping to it.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(starts, neighbours, is_goal, |_| 0)
+}
+
+// Dijkstra's search steered toward the goal by `heuristic`, an estimate of
+// the cost left from a node. The estimate must never be more than the real
+// cost, and must not drop by more than the cost of any one step, or the
+// paths found may not be the cheapest.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut heuristic: impl FnMut(&N) -> usize,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new();
+    // the cheapest way found so far to each node that hasn't been settled
+    let mut best: HashMap<N, (usize, Option<N>)> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        best.insert(start.clone(), (0, None));
+        queue.push(Entry {
+            priority: heuristic(&start),
+            cost: 0,
+            node: start,
+        });
+    }
+
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        if search.costs.contains_key(&node) {
+            // already settled by a cheaper entry
+            continue;
+        }
+        if let Some((_, Some(parent))) = best.remove(&node) {
+            search.parents.insert(node.clone(), parent);
+        }
+        search.costs.insert(node.clone(), cost);
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if search.costs.contains_key(&next) {
+                continue;
+            }
+            if best.get(&next).is_none_or(|&(known, _)| next_cost < known) {
+                best.insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+// A node waiting in the queue, ordered so the BinaryHeap (a max-heap) pops
+// the lowest priority first.
+struct Entry<N> {
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Entry<N>) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Entry<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Entry<N> {
+    fn cmp(&self, other: &Entry<N>) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2;
+
+    // 0 - 1 - 2 - 3 - 4 - 5, with a shortcut from 1 to 4
+    fn line(n: &u32) -> Vec<u32> {
+        let mut next = vec![];
+        if *n > 0 {
+            next.push(n - 1);
+        }
+        if *n < 5 {
+            next.push(n + 1);
+        }
+        if *n == 1 {
+            next.push(4);
+        }
+        next
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([0], line, |&n| n == 5);
+        assert_eq!(search.goal(), Some(&5));
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.path(), Some(vec![0, 1, 4, 5]));
+
+        // from several starts, each node is reached from the nearest
+        let search = bfs([0, 3], line, |_| false);
+        assert_eq!(search.reached().count(), 6);
+        assert_eq!(search.cost(&2), Some(1));
+        assert_eq!(search.path_to(&2), Some(vec![3, 2]));
+        assert_eq!(search.goal(), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // the shortcut is expensive, so walking round is cheaper
+        let weighted = |n: &u32| {
+            line(n)
+                .into_iter()
+                .map(|next| (next, if next.abs_diff(*n) > 1 { 10 } else { 2 }))
+                .collect::<Vec<_>>()
+        };
+        let search = dijkstra([0], weighted, |&n| n == 5);
+        assert_eq!(search.goal_cost(), Some(10));
+        assert_eq!(search.path(), Some(vec![0, 1, 2, 3, 4, 5]));
+        assert_eq!(dijkstra([0], weighted, |&n| n == 9).goal(), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // a 5x5 room with a wall down the middle, open at the bottom
+        let end = Point2::new(4, 0);
+        let open = |p: &Point2<i32>| {
+            (0..5).contains(&p.x) && (0..5).contains(&p.y) && (p.x != 2 || p.y == 4)
+        };
+        let neighbours = |p: &Point2<i32>| {
+            p.orthogonal()
+                .filter(open)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let search = astar(
+            [Point2::new(0, 0)],
+            neighbours,
+            |&p| p == end,
+            |p| p.manhattan(end) as usize,
+        );
+        assert_eq!(search.goal_cost(), Some(12));
+        assert_eq!(search.path().unwrap().len(), 13);
+        assert_eq!(
+            dijkstra([Point2::new(0, 0)], neighbours, |&p| p == end).goal_cost(),
+            Some(12)
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+use std::fmt::Display;
+use std::io::BufRead;
+
+use crate::bench::{self, Timings};
+use crate::error::AocError;
+use crate::frames::{self, Animated, Recorder};
+use crate::generate::{self, Generate};
+
+// Every day parses its input once, then answers both parts from the parsed
+// data. Parts only get a shared reference, so a part that needs to mutate
+// (sand in a cave, crates on a boat) works on its own copy.
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+// A day that can answer both parts in one pass over a reader, without ever
+// holding the whole input, for inputs too big to read into a String.
+pub trait Streaming: Solution {
+    fn stream(reader: &mut dyn BufRead) -> Result<(Self::Output1, Self::Output2), AocError>;
+}
+
+pub fn stream<S: Streaming>(reader: &mut dyn BufRead) -> Result<[String; 2], AocError> {
+    let (part1, part2) = S::stream(reader)?;
+    Ok([part1.to_string(), part2.to_string()])
+}
+
+// A day whose input can be read under more than one set of rules, picked by
+// name with `run --rules`. Plain `parse` reads it the way the puzzle does.
+pub trait RuleSets: Solution {
+    fn parse_with_rules(input: &str, rules: &str) -> Result<Self::Input, AocError>;
+}
+
+// The answer for a part nobody has solved yet.
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+fn answer<S: Solution>(parsed: &S::Input, part: u8) -> String {
+    match part {
+        1 => S::part1(parsed).to_string(),
+        2 => S::part2(parsed).to_string(),
+        _ => panic!("There is no part {part}"),
+    }
+}
+
+// An answer some inputs don't have, like a path to somewhere unreachable.
+// Prints the answer if there is one, or says what's missing.
+pub struct Maybe<T> {
+    pub answer: Option<T>,
+    pub missing: &'static str,
+}
+
+impl<T> Maybe<T> {
+    pub fn new(answer: Option<T>, missing: &'static str) -> Maybe<T> {
+        Maybe { answer, missing }
+    }
+}
+
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.answer {
+            Some(answer) => write!(f, "{answer}"),
+            None => write!(f, "{}", self.missing),
+        }
+    }
+}
+
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<String, AocError> {
+    Ok(answer::<S>(&S::parse(input)?, part))
+}
+
+pub fn solve_with_rules<S: RuleSets>(
+    input: &str,
+    part: u8,
+    rules: &str,
+) -> Result<String, AocError> {
+    Ok(answer::<S>(&S::parse_with_rules(input, rules)?, part))
+}
+
+type SolveWithRulesFn = fn(&str, u8, &str) -> Result<String, AocError>;
+type RecordFn = fn(&str, u8, &mut Recorder) -> Result<(), AocError>;
+type StreamFn = fn(&mut dyn BufRead) -> Result<[String; 2], AocError>;
+
+// A day's solution with its types erased, so the runner can keep every day
+// in one list.
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, u8) -> Result<String, AocError>,
+    pub bench: fn(&str, usize) -> Result<Timings, AocError>,
+    // only for days with a simulation worth watching
+    pub record: Option<RecordFn>,
+    // only for days that can make up their own inputs
+    pub generate: Option<fn(u64, usize) -> String>,
+    // only for days that can read their input a line at a time
+    pub stream: Option<StreamFn>,
+    // only for days with rule sets to pick from
+    pub solve_with_rules: Option<SolveWithRulesFn>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8) -> Day {
+        Day {
+            number,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+            record: None,
+            generate: None,
+            stream: None,
+            solve_with_rules: None,
+        }
+    }
+
+    pub const fn animated<S: Animated>(self) -> Day {
+        Day {
+            record: Some(frames::record::<S>),
+            ..self
+        }
+    }
+
+    pub const fn generated<S: Generate>(self) -> Day {
+        Day {
+            generate: Some(generate::generate::<S>),
+            ..self
+        }
+    }
+
+    pub const fn streaming<S: Streaming>(self) -> Day {
+        Day {
+            stream: Some(stream::<S>),
+            ..self
+        }
+    }
+
+    pub const fn with_rules<S: RuleSets>(self) -> Day {
+        Day {
+            solve_with_rules: Some(solve_with_rules::<S>),
+            ..self
+        }
+    }
+}
+
+// Generates a test for each listed part of each example fixture under
+// examples/, fed through the same input normalisation as the runner:
+//
+//     example_tests! {
+//         Day05,
+//         example: "05/example.txt" { part1 = "CMZ", part2 = "MCD" },
+//     }
+//
+// Leave out a part that isn't solved yet; attributes like #[ignore] can go in
+// front of a part.
+#[macro_export]
+macro_rules! example_tests {
+    ($day:ty, $($name:ident: $file:literal { $($(#[$attr:meta])* $part:ident = $expected:expr),* $(,)? }),* $(,)?) => {
+        $(
+            mod $name {
+                use super::*;
+                use $crate::solution::Solution;
+
+                // a fresh fixture with no answers listed yet still compiles
+                #[allow(dead_code)]
+                fn parse() -> <$day as Solution>::Input {
+                    let input = $crate::input::normalize(include_str!(concat!(
+                        env!("CARGO_MANIFEST_DIR"),
+                        "/examples/",
+                        $file
+                    )));
+                    <$day as Solution>::parse(&input).unwrap()
+                }
+
+                $(
+                    #[test]
+                    $(#[$attr])*
+                    fn $part() {
+                        assert_eq!(<$day as Solution>::$part(&parse()).to_string(), $expected.to_string());
+                    }
+                )*
+            }
+        )*
+    };
+}
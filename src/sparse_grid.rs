@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::point::Point2;
+
+// A grid with no fixed size, storing only the cells that have been set. Any
+// i32 coordinates work, negative ones included, and it keeps track of the
+// smallest box holding every occupied cell so it can be drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<i32>, T>,
+    bounds: Option<(Point2<i32>, Point2<i32>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, pos: Point2<i32>) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point2<i32>) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Point2<i32>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    // Sets a cell, returning what was there before.
+    pub fn insert(&mut self, pos: Point2<i32>, value: T) -> Option<T> {
+        self.grow_bounds(pos);
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Point2<i32>) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+        if let Some((min, max)) = self.bounds {
+            if pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y {
+                // it may have been holding the box open, so measure again
+                self.bounds = None;
+                let positions: Vec<Point2<i32>> = self.cells.keys().copied().collect();
+                for pos in positions {
+                    self.grow_bounds(pos);
+                }
+            }
+        }
+        Some(removed)
+    }
+
+    fn grow_bounds(&mut self, pos: Point2<i32>) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Point2::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Point2::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ),
+            None => (pos, pos),
+        });
+    }
+
+    // The top left and bottom right corners of the occupied region, both
+    // inclusive, or None if nothing's been set.
+    pub fn bounds(&self) -> Option<(Point2<i32>, Point2<i32>)> {
+        self.bounds
+    }
+
+    // The occupied cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i32>, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    // Draws the occupied region a row per line, with y growing down the
+    // page. `cell` gets None for the gaps between occupied cells.
+    pub fn render<F: Fn(Option<&T>) -> char>(&self, cell: F) -> Render<'_, T, F> {
+        Render { grid: self, cell }
+    }
+}
+
+pub struct Render<'a, T, F> {
+    grid: &'a SparseGrid<T>,
+    cell: F,
+}
+
+impl<T, F: Fn(Option<&T>) -> char> fmt::Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some((min, max)) = self.grid.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            if y != min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                write!(f, "{}", (self.cell)(self.grid.get(Point2::new(x, y))))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point2::new(2, -1), 'a');
+        grid.insert(Point2::new(-3, 4), 'b');
+        grid.insert(Point2::new(0, 0), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some((Point2::new(-3, -1), Point2::new(2, 4)))
+        );
+        assert_eq!(grid.insert(Point2::new(0, 0), 'd'), Some('c'));
+        assert_eq!(grid.len(), 3);
+
+        grid.remove(Point2::new(-3, 4));
+        assert_eq!(grid.bounds(), Some((Point2::new(0, -1), Point2::new(2, 0))));
+        grid.remove(Point2::new(0, 0));
+        grid.remove(Point2::new(2, -1));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.render(|_: Option<&bool>| '#').to_string(), "");
+        grid.insert(Point2::new(-1, -1), true);
+        grid.insert(Point2::new(1, 0), false);
+        let picture = grid.render(|cell| match cell {
+            Some(true) => '#',
+            Some(false) => 'o',
+            None => '.',
+        });
+        assert_eq!(picture.to_string(), "#..\n..o");
+    }
+}
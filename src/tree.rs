@@ -0,0 +1,126 @@
+use std::ops::{Index, IndexMut};
+
+// Identifies a node in a `Tree`. Only meaningful for the tree it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    data: T,
+}
+
+// A tree whose nodes all live in one Vec and point at each other by index,
+// so a node can know its parent and children without fighting the borrow
+// checker. Nodes are never removed.
+#[derive(Debug, Clone)]
+pub struct Tree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T> Tree<T> {
+    pub fn new(root: T) -> Tree<T> {
+        Tree {
+            nodes: vec![Node {
+                parent: None,
+                children: Vec::new(),
+                data: root,
+            }],
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    // Always false, as a tree starts with its root.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn add_child(&mut self, parent: NodeId, data: T) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            parent: Some(parent),
+            children: Vec::new(),
+            data,
+        });
+        self.nodes[parent.0].children.push(id);
+        id
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id.0].children
+    }
+
+    // The first child of `id` whose data matches.
+    pub fn find_child(&self, id: NodeId, matches: impl Fn(&T) -> bool) -> Option<NodeId> {
+        self.children(id)
+            .iter()
+            .copied()
+            .find(|&child| matches(&self[child]))
+    }
+
+    // `id` and everything below it, parents before their children.
+    pub fn descendants(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack = vec![id];
+        std::iter::from_fn(move || {
+            let next = stack.pop()?;
+            stack.extend(self.children(next).iter().rev());
+            Some(next)
+        })
+    }
+
+    // The nodes above `id`, starting with its parent and ending at the root.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self.parent(id), move |&id| self.parent(id))
+    }
+}
+
+impl<T> Index<NodeId> for Tree<T> {
+    type Output = T;
+
+    fn index(&self, id: NodeId) -> &T {
+        &self.nodes[id.0].data
+    }
+}
+
+impl<T> IndexMut<NodeId> for Tree<T> {
+    fn index_mut(&mut self, id: NodeId) -> &mut T {
+        &mut self.nodes[id.0].data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree() {
+        let mut tree = Tree::new("/");
+        let root = tree.root();
+        let a = tree.add_child(root, "a");
+        let e = tree.add_child(a, "e");
+        let d = tree.add_child(root, "d");
+        tree[d] = "dd";
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.children(root), &[a, d]);
+        assert_eq!(tree.parent(e), Some(a));
+        assert_eq!(tree.parent(root), None);
+        assert_eq!(tree.find_child(root, |&name| name == "dd"), Some(d));
+        assert_eq!(tree.find_child(root, |&name| name == "e"), None);
+        let names: Vec<&str> = tree.descendants(root).map(|id| tree[id]).collect();
+        assert_eq!(names, vec!["/", "a", "e", "dd"]);
+        assert_eq!(tree.ancestors(e).collect::<Vec<_>>(), vec![a, root]);
+    }
+}